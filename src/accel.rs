@@ -1,12 +1,36 @@
 //! Accelerometer registers.
 
+mod bank;
 mod conversions;
 mod types;
 
+pub use bank::*;
+pub use conversions::*;
 pub use types::*;
 
+use crate::RegisterError;
 use bitfield_struct::bitfield;
 
+/// Implements a checked setter for a 7-bit field below a reserved most significant bit.
+macro_rules! seven_bit_setter {
+    ($type:ident, $try_with:ident, $with:ident) => {
+        impl $type {
+            #[doc = concat!(
+                "Checked variant of [`", stringify!($with), "`](Self::", stringify!($with), ")."
+            )]
+            ///
+            /// Fails with [`RegisterError::ValueOutOfRange`] instead of truncating values
+            /// above 127, keeping the reserved bit clear.
+            pub const fn $try_with(self, value: u8) -> Result<Self, RegisterError> {
+                if value > 0x7F {
+                    return Err(RegisterError::ValueOutOfRange);
+                }
+                Ok(self.$with(value))
+            }
+        }
+    };
+}
+
 /// The I2C bus address.
 ///
 /// For linear acceleration the default (factory) 7-bit slave address is `0011001b`.
@@ -24,7 +48,7 @@ pub const DEFAULT_DEVICE_ADDRESS: u8 = 0b0011001;
 #[allow(dead_code)]
 #[allow(non_camel_case_types)]
 #[allow(missing_docs)]
-#[derive(Copy, Clone, PartialEq, Eq)]
+#[derive(Copy, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
 #[cfg_attr(feature = "defmt", derive(defmt::Format))]
 pub enum RegisterAddress {
     /// See [`ControlRegister1A`].
@@ -67,7 +91,7 @@ pub enum RegisterAddress {
     INT1_THS_A = 0x32,
     /// See [`Int1DurationRegisterA`].
     INT1_DURATION_A = 0x33,
-    /// See [`Int1ConfigurationRegisterA`].
+    /// See [`Int2ConfigurationRegisterA`].
     INT2_CFG_A = 0x34,
     /// See [`Int2SourceRegisterA`].
     INT2_SRC_A = 0x35,
@@ -94,6 +118,67 @@ impl RegisterAddress {
     pub const fn addr(&self) -> u8 {
         *self as u8
     }
+
+    /// Returns the datasheet name of the register, e.g. `"CTRL_REG1_A"`.
+    pub const fn name(self) -> &'static str {
+        match self {
+            Self::CTRL_REG1_A => "CTRL_REG1_A",
+            Self::CTRL_REG2_A => "CTRL_REG2_A",
+            Self::CTRL_REG3_A => "CTRL_REG3_A",
+            Self::CTRL_REG4_A => "CTRL_REG4_A",
+            Self::CTRL_REG5_A => "CTRL_REG5_A",
+            Self::CTRL_REG6_A => "CTRL_REG6_A",
+            Self::REFERENCE_A => "REFERENCE_A",
+            Self::STATUS_REG_A => "STATUS_REG_A",
+            Self::OUT_X_L_A => "OUT_X_L_A",
+            Self::OUT_X_H_A => "OUT_X_H_A",
+            Self::OUT_Y_L_A => "OUT_Y_L_A",
+            Self::OUT_Y_H_A => "OUT_Y_H_A",
+            Self::OUT_Z_L_A => "OUT_Z_L_A",
+            Self::OUT_Z_H_A => "OUT_Z_H_A",
+            Self::FIFO_CTRL_REG_A => "FIFO_CTRL_REG_A",
+            Self::FIFO_SRC_REG_A => "FIFO_SRC_REG_A",
+            Self::INT1_CFG_A => "INT1_CFG_A",
+            Self::INT1_SRC_A => "INT1_SRC_A",
+            Self::INT1_THS_A => "INT1_THS_A",
+            Self::INT1_DURATION_A => "INT1_DURATION_A",
+            Self::INT2_CFG_A => "INT2_CFG_A",
+            Self::INT2_SRC_A => "INT2_SRC_A",
+            Self::INT2_THS_A => "INT2_THS_A",
+            Self::INT2_DURATION_A => "INT2_DURATION_A",
+            Self::CLICK_CFG_A => "CLICK_CFG_A",
+            Self::CLICK_SRC_A => "CLICK_SRC_A",
+            Self::CLICK_THS_A => "CLICK_THS_A",
+            Self::TIME_LIMIT_A => "TIME_LIMIT_A",
+            Self::TIME_LATENCY_A => "TIME_LATENCY_A",
+            Self::TIME_WINDOW_A => "TIME_WINDOW_A",
+        }
+    }
+
+    /// Determines whether the register can be read. All accelerometer registers are readable.
+    pub const fn is_readable(self) -> bool {
+        true
+    }
+
+    /// Determines whether the register can be written, as per the datasheet access column.
+    ///
+    /// The status, output and interrupt source registers are read-only.
+    pub const fn is_writable(self) -> bool {
+        !matches!(
+            self,
+            Self::STATUS_REG_A
+                | Self::OUT_X_L_A
+                | Self::OUT_X_H_A
+                | Self::OUT_Y_L_A
+                | Self::OUT_Y_H_A
+                | Self::OUT_Z_L_A
+                | Self::OUT_Z_H_A
+                | Self::FIFO_SRC_REG_A
+                | Self::INT1_SRC_A
+                | Self::INT2_SRC_A
+                | Self::CLICK_SRC_A
+        )
+    }
 }
 
 impl From<RegisterAddress> for u8 {
@@ -102,9 +187,33 @@ impl From<RegisterAddress> for u8 {
     }
 }
 
+/// The first output register, [`OUT_X_L_A`](RegisterAddress::OUT_X_L_A), for burst reads
+/// of [`ACCEL_OUT_LEN`] bytes.
+///
+/// The accelerometer only increments the register address if the most significant bit
+/// of the sub-address is set, i.e. the sub-address to write is `ACCEL_OUT_START | 0x80`.
+/// See [`Register::read_command`](crate::Register::read_command).
+pub const ACCEL_OUT_START: u8 = RegisterAddress::OUT_X_L_A.addr();
+
+/// The number of output register bytes, from [`OUT_X_L_A`](RegisterAddress::OUT_X_L_A)
+/// through [`OUT_Z_H_A`](RegisterAddress::OUT_Z_H_A).
+pub const ACCEL_OUT_LEN: usize = 6;
+
 /// [`CTRL_REG1_A`](RegisterAddress::CTRL_REG1_A) (20h)
+///
+/// Like all registers, this register can be configured in a `const` context:
+///
+/// ```
+/// # use lsm303dlhc_registers::accel::*;
+/// const CTRL_REG1_A: u8 = ControlRegister1A::new()
+///     .with_output_data_rate(AccelOdr::Hz400)
+///     .into_bits();
+///
+/// assert_eq!(CTRL_REG1_A, 0b0111_0111);
+/// ```
 #[bitfield(u8, order = Msb)]
-#[derive(PartialEq, Eq)]
+#[derive(PartialEq, Eq, Hash)]
+#[must_use]
 #[cfg_attr(feature = "defmt", derive(defmt::Format))]
 pub struct ControlRegister1A {
     /// Data rate selection.
@@ -130,18 +239,70 @@ pub struct ControlRegister1A {
 
 writable_register!(ControlRegister1A, RegisterAddress::CTRL_REG1_A);
 
+impl ControlRegister1A {
+    /// Creates a register from its fields.
+    pub const fn from_parts(odr: AccelOdr, axes: Axes, low_power: bool) -> Self {
+        Self::new()
+            .with_output_data_rate(odr)
+            .with_low_power_enable(low_power)
+            .with_x_enable(axes.x())
+            .with_y_enable(axes.y())
+            .with_z_enable(axes.z())
+    }
+
+    /// Determines whether the specified axis is enabled.
+    pub const fn axis_enabled(&self, axis: AccelAxis) -> bool {
+        match axis {
+            AccelAxis::X => self.x_enable(),
+            AccelAxis::Y => self.y_enable(),
+            AccelAxis::Z => self.z_enable(),
+        }
+    }
+
+    /// Returns the number of enabled axes.
+    pub const fn enabled_count(&self) -> u8 {
+        self.x_enable() as u8 + self.y_enable() as u8 + self.z_enable() as u8
+    }
+
+    /// Returns the effective output data rate in Hz, taking the power mode into account.
+    ///
+    /// In low-power mode, [`LpHz1620NormalHz5376`](AccelOdr::LpHz1620NormalHz5376)
+    /// runs at 5.376 kHz instead of 1.344 kHz. Returns `None` if the sensor is powered
+    /// down, or for [`LpHz1620`](AccelOdr::LpHz1620) in normal mode, which is not a valid
+    /// combination.
+    pub fn sample_rate_hz(&self) -> Option<f32> {
+        match (self.output_data_rate(), self.low_power_enable()) {
+            (AccelOdr::Disabled, _) => None,
+            (odr, false) if odr.is_low_power_only() => None,
+            (AccelOdr::LpHz1620NormalHz5376, true) => Some(5376.0),
+            (odr, _) => Some(odr.frequency_hz()),
+        }
+    }
+
+    /// Returns the approximate signal bandwidth in Hz, i.e. half the
+    /// [effective data rate](Self::sample_rate_hz).
+    ///
+    /// This is an approximation for planning anti-aliasing and filtering, not a measured
+    /// -3 dB bandwidth; see also [`HighpassCutoff::cutoff_hz`]. Returns `0.0` if the sensor
+    /// is powered down or the data rate is invalid in the configured power mode.
+    pub fn bandwidth_hz(&self) -> f32 {
+        self.sample_rate_hz().map_or(0.0, |hz| hz / 2.0)
+    }
+}
+
 /// [`CTRL_REG2_A`](RegisterAddress::CTRL_REG2_A) (21h)
 #[bitfield(u8, order = Msb)]
-#[derive(PartialEq, Eq)]
+#[derive(PartialEq, Eq, Hash)]
+#[must_use]
 #[cfg_attr(feature = "defmt", derive(defmt::Format))]
 pub struct ControlRegister2A {
     /// High-pass filter mode selection.
     #[bits(2, access = RW)]
     pub hpm: HighpassFilterMode,
 
-    /// High-pass filter Cutoff frequency selection
+    /// High-pass filter cutoff frequency selection
     #[bits(2, access = RW)]
-    pub hpcf: u8, // TODO: Add enum
+    pub hpcf: HighpassCutoff,
 
     /// Filter data selection
     #[bits(1, access = RW)]
@@ -162,9 +323,46 @@ pub struct ControlRegister2A {
 
 writable_register!(ControlRegister2A, RegisterAddress::CTRL_REG2_A);
 
+impl ControlRegister2A {
+    /// Configures the high-pass filter to use a reference signal for filtering.
+    ///
+    /// Returns this register with the filter mode set to [`HighpassFilterMode::ReferenceSignal`],
+    /// together with the [`ReferenceRegisterA`] holding the `reference` value. Both registers
+    /// need to be written to the sensor.
+    pub const fn use_reference(self, reference: u8) -> (Self, ReferenceRegisterA) {
+        (
+            self.with_hpm(HighpassFilterMode::ReferenceSignal),
+            ReferenceRegisterA::new().with_reference(reference),
+        )
+    }
+
+    /// Decodes the high-pass filter configuration.
+    pub const fn filter_config(&self) -> FilterConfig {
+        FilterConfig {
+            mode: self.hpm(),
+            cutoff: self.hpcf(),
+            data_selected: self.fds(),
+            click: self.hpclick(),
+            int2: self.hpis2(),
+            int1: self.hpis1(),
+        }
+    }
+
+    /// Applies the high-pass filter configuration; see [`filter_config`](Self::filter_config).
+    pub const fn with_filter_config(self, config: FilterConfig) -> Self {
+        self.with_hpm(config.mode)
+            .with_hpcf(config.cutoff)
+            .with_fds(config.data_selected)
+            .with_hpclick(config.click)
+            .with_hpis2(config.int2)
+            .with_hpis1(config.int1)
+    }
+}
+
 /// [`CTRL_REG3_A`](RegisterAddress::CTRL_REG3_A) (22h)
 #[bitfield(u8, order = Msb)]
-#[derive(PartialEq, Eq)]
+#[derive(PartialEq, Eq, Hash)]
+#[must_use]
 #[cfg_attr(feature = "defmt", derive(defmt::Format))]
 pub struct ControlRegister3A {
     /// Enable CLICK interrupt on INT1
@@ -204,9 +402,22 @@ pub struct ControlRegister3A {
 
 writable_register!(ControlRegister3A, RegisterAddress::CTRL_REG3_A);
 
+impl ControlRegister3A {
+    /// Routes exactly the specified interrupt sources to the INT1 pad.
+    pub const fn with_routing(self, routing: Int1Routing) -> Self {
+        Self::from_bits((self.into_bits() & !Int1Routing::ALL.bits()) | routing.bits())
+    }
+
+    /// Returns the interrupt sources routed to the INT1 pad.
+    pub const fn routing(&self) -> Int1Routing {
+        Int1Routing::from_bits(self.into_bits())
+    }
+}
+
 /// [`CTRL_REG4_A`](RegisterAddress::CTRL_REG4_A) (23h)
 #[bitfield(u8, order = Msb)]
-#[derive(PartialEq, Eq)]
+#[derive(PartialEq, Eq, Hash)]
+#[must_use]
 #[cfg_attr(feature = "defmt", derive(defmt::Format))]
 pub struct ControlRegister4A {
     /// Block data update.
@@ -239,8 +450,13 @@ pub struct ControlRegister4A {
     #[bits(1, access = RW)]
     pub high_resolution: bool,
 
-    #[bits(2, default = 0b00)]
-    zeros_12: u8,
+    /// Self-test enable.
+    ///
+    /// The LSM303DLHC datasheet documents these bits (`ST1`, `ST0`) as reserved and requires
+    /// them to be kept at `0`, i.e. [`SelfTest::Disabled`]. The self-test modes are not
+    /// supported on this device.
+    #[bits(2, access = RW, default = SelfTest::Disabled)]
+    pub self_test: SelfTest,
 
     /// SPI serial interface mode.
     ///
@@ -252,9 +468,33 @@ pub struct ControlRegister4A {
 
 writable_register!(ControlRegister4A, RegisterAddress::CTRL_REG4_A);
 
+impl ControlRegister4A {
+    /// Creates a register from its most commonly configured fields,
+    /// leaving the self-test and interface settings at their defaults.
+    pub const fn from_parts(
+        full_scale: Sensitivity,
+        high_resolution: bool,
+        block_data_update: bool,
+    ) -> Self {
+        Self::new()
+            .with_full_scale(full_scale)
+            .with_high_resolution(high_resolution)
+            .with_block_data_update(block_data_update)
+    }
+
+    /// Returns a register with only the [`block_data_update`](Self::block_data_update) bit configured.
+    ///
+    /// While enabled, the output registers are only updated after both the low and the high byte
+    /// of the previous sample have been read, see e.g. [`OutXLowA`] and [`OutXHighA`].
+    pub const fn bdu(enable: bool) -> Self {
+        Self::new().with_block_data_update(enable)
+    }
+}
+
 /// [`CTRL_REG5_A`](RegisterAddress::CTRL_REG5_A) (24h)
 #[bitfield(u8, order = Msb)]
-#[derive(PartialEq, Eq)]
+#[derive(PartialEq, Eq, Hash)]
+#[must_use]
 #[cfg_attr(feature = "defmt", derive(defmt::Format))]
 pub struct ControlRegister5A {
     /// Reboot memory content
@@ -295,9 +535,39 @@ pub struct ControlRegister5A {
 
 writable_register!(ControlRegister5A, RegisterAddress::CTRL_REG5_A);
 
+impl ControlRegister5A {
+    /// Returns a register with only the [`boot`](Self::boot) bit set.
+    ///
+    /// Writing this register reboots the memory content, bringing the sensor into a
+    /// known state. The bit is cleared by the sensor once the reboot has completed.
+    pub const fn reboot() -> Self {
+        Self::new().with_boot(true)
+    }
+
+    /// Decodes the interrupt latch and 4D detection configuration.
+    pub const fn latch_config(&self) -> LatchConfig {
+        LatchConfig {
+            int1_latched: self.lir_int1(),
+            int2_latched: self.lir_int2(),
+            int1_4d: self.d4d_int1(),
+            int2_4d: self.d4d_int2(),
+        }
+    }
+
+    /// Applies the interrupt latch and 4D detection configuration;
+    /// see [`latch_config`](Self::latch_config).
+    pub const fn with_latch_config(self, config: LatchConfig) -> Self {
+        self.with_lir_int1(config.int1_latched)
+            .with_lir_int2(config.int2_latched)
+            .with_d4d_int1(config.int1_4d)
+            .with_d4d_int2(config.int2_4d)
+    }
+}
+
 /// [`CTRL_REG6_A`](RegisterAddress::CTRL_REG6_A) (25h)
 #[bitfield(u8, order = Msb)]
-#[derive(PartialEq, Eq)]
+#[derive(PartialEq, Eq, Hash)]
+#[must_use]
 #[cfg_attr(feature = "defmt", derive(defmt::Format))]
 pub struct ControlRegister6A {
     /// CLICK interrupt enable on PAD2.
@@ -342,12 +612,44 @@ pub struct ControlRegister6A {
 
 writable_register!(ControlRegister6A, RegisterAddress::CTRL_REG6_A);
 
+impl ControlRegister6A {
+    /// Returns a register with only the interrupt polarity configured.
+    ///
+    /// The polarity applies to both the INT1 and INT2 pads, so the GPIO lines of
+    /// both pins need to be interpreted accordingly.
+    ///
+    /// To read the polarity back, use [`is_active_low`](Self::is_active_low) or the [`active_low`](Self::active_low) getter.
+    ///
+    /// * `false` - Interrupts are active high
+    /// * `true` - Interrupts are active low
+    pub const fn active_low_interrupts(enable: bool) -> Self {
+        Self::new().with_active_low(enable)
+    }
+
+    /// Determines whether the INT1 and INT2 pads are active low.
+    pub const fn is_active_low(&self) -> bool {
+        self.active_low()
+    }
+
+    /// Routes exactly the specified interrupt sources to the INT2 pad,
+    /// leaving the interrupt polarity unchanged.
+    pub const fn with_routing(self, routing: Int2Routing) -> Self {
+        Self::from_bits((self.into_bits() & !Int2Routing::ALL.bits()) | routing.bits())
+    }
+
+    /// Returns the interrupt sources routed to the INT2 pad.
+    pub const fn routing(&self) -> Int2Routing {
+        Int2Routing::from_bits(self.into_bits())
+    }
+}
+
 /// This register sets the acceleration value taken as a reference for the high-pass filter output.
 /// (See Doc ID 16941 Rev 1. for the LSM303DLH, non -C version)
 ///
 /// [`REFERENCE_A`](RegisterAddress::REFERENCE_A) (26h)
 #[bitfield(u8, order = Msb)]
-#[derive(PartialEq, Eq)]
+#[derive(PartialEq, Eq, Hash)]
+#[must_use]
 #[cfg_attr(feature = "defmt", derive(defmt::Format))]
 pub struct ReferenceRegisterA {
     /// Reference value for interrupt generation.
@@ -357,9 +659,36 @@ pub struct ReferenceRegisterA {
 
 writable_register!(ReferenceRegisterA, RegisterAddress::REFERENCE_A);
 
+impl ReferenceRegisterA {
+    /// Returns the reference in g for the specified full scale.
+    ///
+    /// The reference is a two's complement value at the resolution of the output
+    /// high bytes, i.e. 1 LSB = full-scale / 128.
+    pub fn reference_g(&self, full_scale: Sensitivity) -> f32 {
+        self.reference() as i8 as f32 * SensitivityScale(full_scale).threshold_g_per_lsb()
+    }
+
+    /// Creates a register with the reference closest to `g` for the specified full scale,
+    /// saturating at the representable range.
+    pub fn from_g(g: f32, full_scale: Sensitivity) -> Self {
+        let lsb = g / SensitivityScale(full_scale).threshold_g_per_lsb();
+        let reference = if lsb >= 127.0 {
+            127
+        } else if lsb <= -128.0 {
+            -128
+        } else if lsb >= 0.0 {
+            (lsb + 0.5) as i8
+        } else {
+            (lsb - 0.5) as i8
+        };
+        Self::new().with_reference(reference as u8)
+    }
+}
+
 /// [`STATUS_REG_A`](RegisterAddress::STATUS_REG_A) (27h)
 #[bitfield(u8, order = Msb)]
-#[derive(PartialEq, Eq)]
+#[derive(PartialEq, Eq, Hash)]
+#[must_use]
 #[cfg_attr(feature = "defmt", derive(defmt::Format))]
 pub struct StatusRegisterA {
     /// X-, Y-, and Z-axis data overrun.
@@ -413,6 +742,28 @@ pub struct StatusRegisterA {
 
 readable_register!(StatusRegisterA, RegisterAddress::STATUS_REG_A);
 
+impl StatusRegisterA {
+    /// Returns which axes have new data available.
+    pub const fn data_ready(&self) -> AxisFlags {
+        AxisFlags {
+            x: self.x_data_available(),
+            y: self.y_data_available(),
+            z: self.z_data_available(),
+            all: self.xyz_data_available(),
+        }
+    }
+
+    /// Returns which axes had their data overwritten before it was read.
+    pub const fn overrun(&self) -> AxisFlags {
+        AxisFlags {
+            x: self.x_overrun(),
+            y: self.y_overrun(),
+            z: self.z_overrun(),
+            all: self.zyx_overrun(),
+        }
+    }
+}
+
 /// [`OUT_X_L_A`](RegisterAddress::OUT_X_L_A) (28h)
 ///
 /// Low byte of the 16-bit acceleration value. See [`OutXHighA`] for the high byte.
@@ -423,8 +774,15 @@ readable_register!(StatusRegisterA, RegisterAddress::STATUS_REG_A);
 /// has the lower register address and will be read first.
 /// While the temperature readings follow the same principle, the magnetometer readings
 /// have a different order.
+///
+/// ## Block Data Update
+///
+/// If [`ControlRegister4A::bdu`] is enabled, the output registers are not updated until
+/// both the low and the high byte of a pair have been read. Always read both bytes,
+/// otherwise no further samples become visible.
 #[bitfield(u8, order = Msb)]
-#[derive(PartialEq, Eq)]
+#[derive(PartialEq, Eq, Hash)]
+#[must_use]
 #[cfg_attr(feature = "defmt", derive(defmt::Format))]
 pub struct OutXLowA {
     /// Low byte of the X-axis value.
@@ -446,8 +804,15 @@ readable_register!(OutXLowA, RegisterAddress::OUT_X_L_A);
 /// has the lower register address and will be read first.
 /// While the temperature readings follow the same principle, the magnetometer readings
 /// have a different order.
+///
+/// ## Block Data Update
+///
+/// If [`ControlRegister4A::bdu`] is enabled, the output registers are not updated until
+/// both the low and the high byte of a pair have been read. Always read both bytes,
+/// otherwise no further samples become visible.
 #[bitfield(u8, order = Msb)]
-#[derive(PartialEq, Eq)]
+#[derive(PartialEq, Eq, Hash)]
+#[must_use]
 #[cfg_attr(feature = "defmt", derive(defmt::Format))]
 pub struct OutXHighA {
     /// High byte of the X-axis value.
@@ -469,8 +834,15 @@ readable_register!(OutXHighA, RegisterAddress::OUT_X_H_A);
 /// has the lower register address and will be read first.
 /// While the temperature readings follow the same principle, the magnetometer readings
 /// have a different order.
+///
+/// ## Block Data Update
+///
+/// If [`ControlRegister4A::bdu`] is enabled, the output registers are not updated until
+/// both the low and the high byte of a pair have been read. Always read both bytes,
+/// otherwise no further samples become visible.
 #[bitfield(u8, order = Msb)]
-#[derive(PartialEq, Eq)]
+#[derive(PartialEq, Eq, Hash)]
+#[must_use]
 #[cfg_attr(feature = "defmt", derive(defmt::Format))]
 pub struct OutYLowA {
     /// Low byte of the Y-axis acceleration value.
@@ -492,8 +864,15 @@ readable_register!(OutYLowA, RegisterAddress::OUT_Y_L_A);
 /// has the lower register address and will be read first.
 /// While the temperature readings follow the same principle, the magnetometer readings
 /// have a different order.
+///
+/// ## Block Data Update
+///
+/// If [`ControlRegister4A::bdu`] is enabled, the output registers are not updated until
+/// both the low and the high byte of a pair have been read. Always read both bytes,
+/// otherwise no further samples become visible.
 #[bitfield(u8, order = Msb)]
-#[derive(PartialEq, Eq)]
+#[derive(PartialEq, Eq, Hash)]
+#[must_use]
 #[cfg_attr(feature = "defmt", derive(defmt::Format))]
 pub struct OutYHighA {
     /// High byte of the Y-axis acceleration value.
@@ -515,8 +894,15 @@ readable_register!(OutYHighA, RegisterAddress::OUT_Y_H_A);
 /// has the lower register address and will be read first.
 /// While the temperature readings follow the same principle, the magnetometer readings
 /// have a different order.
+///
+/// ## Block Data Update
+///
+/// If [`ControlRegister4A::bdu`] is enabled, the output registers are not updated until
+/// both the low and the high byte of a pair have been read. Always read both bytes,
+/// otherwise no further samples become visible.
 #[bitfield(u8, order = Msb)]
-#[derive(PartialEq, Eq)]
+#[derive(PartialEq, Eq, Hash)]
+#[must_use]
 #[cfg_attr(feature = "defmt", derive(defmt::Format))]
 pub struct OutZLowA {
     /// Low byte of the Z-axis acceleration value.
@@ -538,8 +924,15 @@ readable_register!(OutZLowA, RegisterAddress::OUT_Z_L_A);
 /// has the lower register address and will be read first.
 /// While the temperature readings follow the same principle, the magnetometer readings
 /// have a different order.
+///
+/// ## Block Data Update
+///
+/// If [`ControlRegister4A::bdu`] is enabled, the output registers are not updated until
+/// both the low and the high byte of a pair have been read. Always read both bytes,
+/// otherwise no further samples become visible.
 #[bitfield(u8, order = Msb)]
-#[derive(PartialEq, Eq)]
+#[derive(PartialEq, Eq, Hash)]
+#[must_use]
 #[cfg_attr(feature = "defmt", derive(defmt::Format))]
 pub struct OutZHighA {
     /// High byte of the Z-axis acceleration value.
@@ -553,7 +946,8 @@ readable_register!(OutZHighA, RegisterAddress::OUT_Z_H_A);
 
 /// [`FIFO_CTRL_REG_A`](RegisterAddress::FIFO_CTRL_REG_A) (2Eh)
 #[bitfield(u8, order = Msb)]
-#[derive(PartialEq, Eq)]
+#[derive(PartialEq, Eq, Hash)]
+#[must_use]
 #[cfg_attr(feature = "defmt", derive(defmt::Format))]
 pub struct FifoControlRegisterA {
     /// FIFO mode selection
@@ -563,7 +957,7 @@ pub struct FifoControlRegisterA {
     /// Trigger selection
     ///
     /// * `false` - Trigger event linked to trigger signal on INT1
-    /// * `true` - Trigger event linked to trigger signal on INT1
+    /// * `true` - Trigger event linked to trigger signal on INT2
     #[bits(1, access = RW)]
     pub trigger_on_int2: bool,
 
@@ -574,9 +968,48 @@ pub struct FifoControlRegisterA {
 
 writable_register!(FifoControlRegisterA, RegisterAddress::FIFO_CTRL_REG_A);
 
-/// [`FIFO_CTRL_REG_A`](RegisterAddress::FIFO_SRC_REG_A) (2Fh)
+impl FifoControlRegisterA {
+    /// Creates a checked FIFO configuration.
+    ///
+    /// The `watermark` (`fth`) is a 5-bit value and has no meaning in [`FifoMode::Bypass`];
+    /// `trigger_int2` only matters in [`FifoMode::Trigger`].
+    ///
+    /// Fails with [`RegisterError::ValueOutOfRange`] if the watermark exceeds 31
+    /// or is nonzero in bypass mode.
+    pub const fn configure(
+        mode: FifoMode,
+        watermark: u8,
+        trigger_int2: bool,
+    ) -> Result<Self, RegisterError> {
+        if watermark > 0b1_1111 || (matches!(mode, FifoMode::Bypass) && watermark != 0) {
+            return Err(RegisterError::ValueOutOfRange);
+        }
+
+        Ok(Self::new()
+            .with_fifo_mode(mode)
+            .with_trigger_on_int2(trigger_int2)
+            .with_fth(watermark))
+    }
+
+    /// Returns the interrupt pad triggering the FIFO in [`FifoMode::Trigger`].
+    pub const fn trigger_source(&self) -> TriggerSource {
+        if self.trigger_on_int2() {
+            TriggerSource::Int2
+        } else {
+            TriggerSource::Int1
+        }
+    }
+
+    /// Selects the interrupt pad triggering the FIFO in [`FifoMode::Trigger`].
+    pub const fn with_trigger_source(self, source: TriggerSource) -> Self {
+        self.with_trigger_on_int2(matches!(source, TriggerSource::Int2))
+    }
+}
+
+/// [`FIFO_SRC_REG_A`](RegisterAddress::FIFO_SRC_REG_A) (2Fh)
 #[bitfield(u8, order = Msb)]
-#[derive(PartialEq, Eq)]
+#[derive(PartialEq, Eq, Hash)]
+#[must_use]
 #[cfg_attr(feature = "defmt", derive(defmt::Format))]
 pub struct FifoSourceRegisterA {
     #[bits(1, access = RO)]
@@ -594,9 +1027,24 @@ pub struct FifoSourceRegisterA {
 
 readable_register!(FifoSourceRegisterA, RegisterAddress::FIFO_SRC_REG_A);
 
-/// [`INT1_CFG_A`](RegisterAddress::INT1_CFG_A) (2Fh)
+/// Returns the number of samples stored in the FIFO.
+///
+/// The 5-bit [`fss`](FifoSourceRegisterA::fss) field can report at most 31 unread samples.
+/// When the FIFO overruns, all 32 levels are filled while `fss` still reads 31; in this case
+/// [`ovrn_fifo`](FifoSourceRegisterA::ovrn_fifo) is set and `32` is returned so that the
+/// FIFO can be drained completely.
+pub const fn fifo_depth(src: FifoSourceRegisterA) -> u8 {
+    if src.ovrn_fifo() {
+        32
+    } else {
+        src.fss()
+    }
+}
+
+/// [`INT1_CFG_A`](RegisterAddress::INT1_CFG_A) (30h)
 #[bitfield(u8, order = Msb)]
-#[derive(PartialEq, Eq)]
+#[derive(PartialEq, Eq, Hash)]
+#[must_use]
 #[cfg_attr(feature = "defmt", derive(defmt::Format))]
 pub struct Int1ConfigurationRegisterA {
     /// AND/OR combination of interrupt events.
@@ -636,7 +1084,8 @@ writable_register!(Int1ConfigurationRegisterA, RegisterAddress::INT1_CFG_A);
 
 /// [`INT1_SRC_A`](RegisterAddress::INT1_SRC_A) (31h)
 #[bitfield(u8, order = Msb)]
-#[derive(PartialEq, Eq)]
+#[derive(PartialEq, Eq, Hash)]
+#[must_use]
 #[cfg_attr(feature = "defmt", derive(defmt::Format))]
 pub struct Int1SourceRegisterA {
     #[bits(1, default = false)]
@@ -673,9 +1122,10 @@ pub struct Int1SourceRegisterA {
 
 readable_register!(Int1SourceRegisterA, RegisterAddress::INT1_SRC_A);
 
-/// [`INT1_SRC_A`](RegisterAddress::INT1_THS_A) (32h)
+/// [`INT1_THS_A`](RegisterAddress::INT1_THS_A) (32h)
 #[bitfield(u8, order = Msb)]
-#[derive(PartialEq, Eq)]
+#[derive(PartialEq, Eq, Hash)]
+#[must_use]
 #[cfg_attr(feature = "defmt", derive(defmt::Format))]
 pub struct Int1ThresholdRegisterA {
     #[bits(1, default = false)]
@@ -687,10 +1137,12 @@ pub struct Int1ThresholdRegisterA {
 }
 
 writable_register!(Int1ThresholdRegisterA, RegisterAddress::INT1_THS_A);
+seven_bit_setter!(Int1ThresholdRegisterA, try_with_threshold, with_threshold);
 
 /// [`INT1_DURATION_A`](RegisterAddress::INT1_DURATION_A) (33h)
 #[bitfield(u8, order = Msb)]
-#[derive(PartialEq, Eq)]
+#[derive(PartialEq, Eq, Hash)]
+#[must_use]
 #[cfg_attr(feature = "defmt", derive(defmt::Format))]
 pub struct Int1DurationRegisterA {
     #[bits(1, default = false)]
@@ -703,10 +1155,19 @@ pub struct Int1DurationRegisterA {
 }
 
 writable_register!(Int1DurationRegisterA, RegisterAddress::INT1_DURATION_A);
+seven_bit_setter!(Int1DurationRegisterA, try_with_duration, with_duration);
+
+impl Int1DurationRegisterA {
+    /// Creates a register from a duration in ticks, saturating at 127 ticks.
+    pub const fn from_ticks(ticks: OdrTicks) -> Self {
+        Self::new().with_duration(ticks.saturating_7bit())
+    }
+}
 
 /// [`INT2_CFG_A`](RegisterAddress::INT2_CFG_A) (34h)
 #[bitfield(u8, order = Msb)]
-#[derive(PartialEq, Eq)]
+#[derive(PartialEq, Eq, Hash)]
+#[must_use]
 #[cfg_attr(feature = "defmt", derive(defmt::Format))]
 pub struct Int2ConfigurationRegisterA {
     /// AND/OR combination of interrupt events.
@@ -764,7 +1225,8 @@ writable_register!(Int2ConfigurationRegisterA, RegisterAddress::INT2_CFG_A);
 
 /// [`INT2_SRC_A`](RegisterAddress::INT2_SRC_A) (35h)
 #[bitfield(u8, order = Msb)]
-#[derive(PartialEq, Eq)]
+#[derive(PartialEq, Eq, Hash)]
+#[must_use]
 #[cfg_attr(feature = "defmt", derive(defmt::Format))]
 pub struct Int2SourceRegisterA {
     #[bits(1, default = false)]
@@ -801,9 +1263,10 @@ pub struct Int2SourceRegisterA {
 
 readable_register!(Int2SourceRegisterA, RegisterAddress::INT2_SRC_A);
 
-/// [`INT2_SRC_A`](RegisterAddress::INT2_THS_A) (36h)
+/// [`INT2_THS_A`](RegisterAddress::INT2_THS_A) (36h)
 #[bitfield(u8, order = Msb)]
-#[derive(PartialEq, Eq)]
+#[derive(PartialEq, Eq, Hash)]
+#[must_use]
 #[cfg_attr(feature = "defmt", derive(defmt::Format))]
 pub struct Int2ThresholdRegisterA {
     #[bits(1, default = false)]
@@ -815,10 +1278,12 @@ pub struct Int2ThresholdRegisterA {
 }
 
 writable_register!(Int2ThresholdRegisterA, RegisterAddress::INT2_THS_A);
+seven_bit_setter!(Int2ThresholdRegisterA, try_with_threshold, with_threshold);
 
 /// [`INT2_DURATION_A`](RegisterAddress::INT2_DURATION_A) (37h)
 #[bitfield(u8, order = Msb)]
-#[derive(PartialEq, Eq)]
+#[derive(PartialEq, Eq, Hash)]
+#[must_use]
 #[cfg_attr(feature = "defmt", derive(defmt::Format))]
 pub struct Int2DurationRegisterA {
     #[bits(1, default = false)]
@@ -831,10 +1296,19 @@ pub struct Int2DurationRegisterA {
 }
 
 writable_register!(Int2DurationRegisterA, RegisterAddress::INT2_DURATION_A);
+seven_bit_setter!(Int2DurationRegisterA, try_with_duration, with_duration);
+
+impl Int2DurationRegisterA {
+    /// Creates a register from a duration in ticks, saturating at 127 ticks.
+    pub const fn from_ticks(ticks: OdrTicks) -> Self {
+        Self::new().with_duration(ticks.saturating_7bit())
+    }
+}
 
 /// [`CLICK_CFG_A`](RegisterAddress::CLICK_CFG_A) (38h)
 #[bitfield(u8, order = Msb)]
-#[derive(PartialEq, Eq)]
+#[derive(PartialEq, Eq, Hash)]
+#[must_use]
 #[cfg_attr(feature = "defmt", derive(defmt::Format))]
 pub struct ClickConfigurationRegisterA {
     #[bits(2)]
@@ -885,9 +1359,28 @@ pub struct ClickConfigurationRegisterA {
 
 writable_register!(ClickConfigurationRegisterA, RegisterAddress::CLICK_CFG_A);
 
+impl ClickConfigurationRegisterA {
+    /// Returns a register enabling single-click detection on the specified axes.
+    pub const fn single_tap(axes: Axes) -> Self {
+        Self::new()
+            .with_xs(axes.x())
+            .with_ys(axes.y())
+            .with_zs(axes.z())
+    }
+
+    /// Returns a register enabling double-click detection on the specified axes.
+    pub const fn double_tap(axes: Axes) -> Self {
+        Self::new()
+            .with_xd(axes.x())
+            .with_yd(axes.y())
+            .with_zd(axes.z())
+    }
+}
+
 /// [`CLICK_SRC_A`](RegisterAddress::CLICK_SRC_A) (39h)
 #[bitfield(u8, order = Msb)]
-#[derive(PartialEq, Eq)]
+#[derive(PartialEq, Eq, Hash)]
+#[must_use]
 #[cfg_attr(feature = "defmt", derive(defmt::Format))]
 pub struct ClickSourceRegisterA {
     #[bits(1)]
@@ -941,7 +1434,8 @@ readable_register!(ClickSourceRegisterA, RegisterAddress::CLICK_SRC_A);
 
 /// [`CLICK_THS_A`](RegisterAddress::CLICK_THS_A) (3Ah)
 #[bitfield(u8, order = Msb)]
-#[derive(PartialEq, Eq)]
+#[derive(PartialEq, Eq, Hash)]
+#[must_use]
 #[cfg_attr(feature = "defmt", derive(defmt::Format))]
 pub struct ClickThresholdRegisterA {
     #[bits(1, default = false)]
@@ -958,10 +1452,12 @@ pub struct ClickThresholdRegisterA {
 }
 
 writable_register!(ClickThresholdRegisterA, RegisterAddress::CLICK_THS_A);
+seven_bit_setter!(ClickThresholdRegisterA, try_with_threshold, with_threshold);
 
 /// [`TIME_LIMIT_A`](RegisterAddress::TIME_LIMIT_A) (3Bh)
 #[bitfield(u8, order = Msb)]
-#[derive(PartialEq, Eq)]
+#[derive(PartialEq, Eq, Hash)]
+#[must_use]
 #[cfg_attr(feature = "defmt", derive(defmt::Format))]
 pub struct ClickTimeLimitRegisterA {
     #[bits(1, default = false)]
@@ -977,10 +1473,23 @@ pub struct ClickTimeLimitRegisterA {
 }
 
 writable_register!(ClickTimeLimitRegisterA, RegisterAddress::TIME_LIMIT_A);
+seven_bit_setter!(
+    ClickTimeLimitRegisterA,
+    try_with_time_limit,
+    with_time_limit
+);
+
+impl ClickTimeLimitRegisterA {
+    /// Creates a register from a duration in ticks, saturating at 127 ticks.
+    pub const fn from_ticks(ticks: OdrTicks) -> Self {
+        Self::new().with_time_limit(ticks.saturating_7bit())
+    }
+}
 
 /// [`TIME_LATENCY_A`](RegisterAddress::TIME_LATENCY_A) (3Ch)
 #[bitfield(u8, order = Msb)]
-#[derive(PartialEq, Eq)]
+#[derive(PartialEq, Eq, Hash)]
+#[must_use]
 #[cfg_attr(feature = "defmt", derive(defmt::Format))]
 pub struct ClickTimeLatencyRegisterA {
     /// Double-click time latency.
@@ -994,9 +1503,17 @@ pub struct ClickTimeLatencyRegisterA {
 
 writable_register!(ClickTimeLatencyRegisterA, RegisterAddress::TIME_LATENCY_A);
 
+impl ClickTimeLatencyRegisterA {
+    /// Creates a register from a duration in ticks.
+    pub const fn from_ticks(ticks: OdrTicks) -> Self {
+        Self::new().with_time_latency(ticks.0)
+    }
+}
+
 /// [`TIME_WINDOW_A`](RegisterAddress::TIME_WINDOW_A) (3Dh)
 #[bitfield(u8, order = Msb)]
-#[derive(PartialEq, Eq)]
+#[derive(PartialEq, Eq, Hash)]
+#[must_use]
 #[cfg_attr(feature = "defmt", derive(defmt::Format))]
 pub struct ClickTimeWindowRegisterA {
     /// Double-click time window.
@@ -1010,9 +1527,164 @@ pub struct ClickTimeWindowRegisterA {
 
 writable_register!(ClickTimeWindowRegisterA, RegisterAddress::TIME_WINDOW_A);
 
+impl ClickTimeWindowRegisterA {
+    /// Creates a register from a duration in ticks.
+    pub const fn from_ticks(ticks: OdrTicks) -> Self {
+        Self::new().with_time_window(ticks.0)
+    }
+}
+
+/// The registers for waking up on motion via INT1; see [`wake_on_motion`].
+///
+/// Write the threshold and duration before the configuration and routing registers,
+/// i.e. in field order.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+pub struct WakeOnMotionConfig {
+    /// The motion threshold.
+    pub int1_ths: Int1ThresholdRegisterA,
+    /// The minimum duration of the motion.
+    pub int1_duration: Int1DurationRegisterA,
+    /// High events on the selected axes, combined with OR.
+    pub int1_cfg: Int1ConfigurationRegisterA,
+    /// Routes the AOI1 interrupt to the INT1 pad.
+    pub ctrl3: ControlRegister3A,
+    /// Latches the interrupt request on INT1.
+    pub ctrl5: ControlRegister5A,
+}
+
+/// Creates the configuration for raising INT1 when the acceleration on any of the
+/// selected axes exceeds `threshold_g` for at least `duration`.
+///
+/// The threshold is rounded to the nearest step of the full scale `fs` (full-scale / 128)
+/// and the duration saturates at 127 ticks; see [`SensitivityScale`] and [`OdrTicks`].
+///
+/// The interrupt is latched: INT1 stays asserted until [`Int1SourceRegisterA`] is read,
+/// so a sleeping host cannot miss a short motion event. Note that
+/// [`ctrl5`](WakeOnMotionConfig::ctrl5) only has the latch bit set; merge it with the
+/// remaining `CTRL_REG5_A` configuration, e.g. if the FIFO is enabled.
+pub fn wake_on_motion(
+    threshold_g: f32,
+    duration: OdrTicks,
+    fs: Sensitivity,
+    axes: Axes,
+) -> WakeOnMotionConfig {
+    WakeOnMotionConfig {
+        int1_ths: Int1ThresholdRegisterA::new()
+            .with_threshold(SensitivityScale(fs).ths_for_g(threshold_g)),
+        int1_duration: Int1DurationRegisterA::from_ticks(duration),
+        int1_cfg: Int1ConfigurationRegisterA::new()
+            .with_xhie_xupe(axes.x())
+            .with_yhie_yupe(axes.y())
+            .with_zhie_zupe(axes.z()),
+        ctrl3: ControlRegister3A::new().with_routing(Int1Routing::AOI1),
+        ctrl5: ControlRegister5A::new().with_lir_int1(true),
+    }
+}
+
+register_masks! {
+    ControlRegister1A => (0xFF, 0x00),
+    ControlRegister2A => (0xFF, 0x00),
+    ControlRegister3A => (0xFE, 0x01),
+    ControlRegister4A => (0xFF, 0x00),
+    ControlRegister5A => (0xCF, 0x30),
+    ControlRegister6A => (0xFA, 0x05),
+    ReferenceRegisterA => (0xFF, 0x00),
+    StatusRegisterA => (0x00, 0x00),
+    OutXLowA => (0x00, 0x00),
+    OutXHighA => (0x00, 0x00),
+    OutYLowA => (0x00, 0x00),
+    OutYHighA => (0x00, 0x00),
+    OutZLowA => (0x00, 0x00),
+    OutZHighA => (0x00, 0x00),
+    FifoControlRegisterA => (0xFF, 0x00),
+    FifoSourceRegisterA => (0x00, 0x00),
+    Int1ConfigurationRegisterA => (0xFF, 0x00),
+    Int1SourceRegisterA => (0x00, 0x80),
+    Int1ThresholdRegisterA => (0x7F, 0x80),
+    Int1DurationRegisterA => (0x7F, 0x80),
+    Int2ConfigurationRegisterA => (0xFF, 0x00),
+    Int2SourceRegisterA => (0x00, 0x80),
+    Int2ThresholdRegisterA => (0x7F, 0x80),
+    Int2DurationRegisterA => (0x7F, 0x80),
+    ClickConfigurationRegisterA => (0x3F, 0xC0),
+    ClickSourceRegisterA => (0x00, 0x80),
+    ClickThresholdRegisterA => (0x7F, 0x80),
+    ClickTimeLimitRegisterA => (0x7F, 0x80),
+    ClickTimeLatencyRegisterA => (0xFF, 0x00),
+    ClickTimeWindowRegisterA => (0xFF, 0x00),
+}
+
+register_por_values! {
+    ControlRegister1A => 0b0000_0111,
+    ControlRegister2A => 0x00,
+    ControlRegister3A => 0x00,
+    ControlRegister4A => 0x00,
+    ControlRegister5A => 0x00,
+    ControlRegister6A => 0x00,
+    ReferenceRegisterA => 0x00,
+    StatusRegisterA => 0x00,
+    OutXLowA => 0x00,
+    OutXHighA => 0x00,
+    OutYLowA => 0x00,
+    OutYHighA => 0x00,
+    OutZLowA => 0x00,
+    OutZHighA => 0x00,
+    FifoControlRegisterA => 0x00,
+    FifoSourceRegisterA => 0x00,
+    Int1ConfigurationRegisterA => 0x00,
+    Int1SourceRegisterA => 0x00,
+    Int1ThresholdRegisterA => 0x00,
+    Int1DurationRegisterA => 0x00,
+    Int2ConfigurationRegisterA => 0x00,
+    Int2SourceRegisterA => 0x00,
+    Int2ThresholdRegisterA => 0x00,
+    Int2DurationRegisterA => 0x00,
+    ClickConfigurationRegisterA => 0x00,
+    ClickSourceRegisterA => 0x00,
+    ClickThresholdRegisterA => 0x00,
+    ClickTimeLimitRegisterA => 0x00,
+    ClickTimeLatencyRegisterA => 0x00,
+    ClickTimeWindowRegisterA => 0x00,
+}
+
+assert_register_addresses! {
+    ControlRegister1A => 0x20,
+    ControlRegister2A => 0x21,
+    ControlRegister3A => 0x22,
+    ControlRegister4A => 0x23,
+    ControlRegister5A => 0x24,
+    ControlRegister6A => 0x25,
+    ReferenceRegisterA => 0x26,
+    StatusRegisterA => 0x27,
+    OutXLowA => 0x28,
+    OutXHighA => 0x29,
+    OutYLowA => 0x2A,
+    OutYHighA => 0x2B,
+    OutZLowA => 0x2C,
+    OutZHighA => 0x2D,
+    FifoControlRegisterA => 0x2E,
+    FifoSourceRegisterA => 0x2F,
+    Int1ConfigurationRegisterA => 0x30,
+    Int1SourceRegisterA => 0x31,
+    Int1ThresholdRegisterA => 0x32,
+    Int1DurationRegisterA => 0x33,
+    Int2ConfigurationRegisterA => 0x34,
+    Int2SourceRegisterA => 0x35,
+    Int2ThresholdRegisterA => 0x36,
+    Int2DurationRegisterA => 0x37,
+    ClickConfigurationRegisterA => 0x38,
+    ClickSourceRegisterA => 0x39,
+    ClickThresholdRegisterA => 0x3A,
+    ClickTimeLimitRegisterA => 0x3B,
+    ClickTimeLatencyRegisterA => 0x3C,
+    ClickTimeWindowRegisterA => 0x3D,
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::{Register, RegisterError, WritableRegister};
 
     #[test]
     #[allow(clippy::unusual_byte_groupings)]
@@ -1026,4 +1698,750 @@ mod tests {
 
         assert_eq!(reg.into_bits(), 0b0111_0_111);
     }
+
+    #[test]
+    #[allow(clippy::unusual_byte_groupings)]
+    fn const_configuration() {
+        const REG: ControlRegister1A = ControlRegister1A::new()
+            .with_output_data_rate(AccelOdr::Hz100)
+            .with_low_power_enable(true)
+            .with_y_enable(false);
+        const BITS: u8 = REG.into_bits();
+
+        assert_eq!(BITS, 0b0101_1_101);
+    }
+
+    #[test]
+    fn highpass_reference() {
+        let (ctrl, reference) = ControlRegister2A::new()
+            .with_hpclick(true)
+            .use_reference(0x42);
+        assert_eq!(ctrl.hpm(), HighpassFilterMode::ReferenceSignal);
+        assert!(ctrl.hpclick());
+        assert_eq!(reference.reference(), 0x42);
+    }
+
+    #[test]
+    fn filter_config_round_trip() {
+        let config = FilterConfig {
+            mode: HighpassFilterMode::AutoresetOnInterrupt,
+            cutoff: HighpassCutoff::Low,
+            data_selected: true,
+            click: false,
+            int2: true,
+            int1: false,
+        };
+        let reg = ControlRegister2A::new().with_filter_config(config);
+        assert_eq!(reg.into_bits(), 0b1110_1010);
+        assert_eq!(reg.filter_config(), config);
+    }
+
+    #[test]
+    fn highpass_cutoff() {
+        let reg = ControlRegister2A::new().with_hpcf(HighpassCutoff::Low);
+        assert_eq!(reg.into_bits(), 0b0010_0000);
+        assert_eq!(reg.hpcf(), HighpassCutoff::Low);
+
+        assert_eq!(HighpassCutoff::Highest.cutoff_hz(AccelOdr::Hz100), 2.0);
+        assert_eq!(HighpassCutoff::Lowest.cutoff_hz(AccelOdr::Hz400), 1.0);
+        assert_eq!(HighpassCutoff::High.cutoff_hz(AccelOdr::Hz50), 0.5);
+    }
+
+    #[test]
+    fn reboot() {
+        assert_eq!(ControlRegister5A::reboot().into_bits(), 0x80);
+    }
+
+    #[test]
+    fn latch_config_round_trip() {
+        let config = LatchConfig {
+            int1_latched: true,
+            int2_latched: false,
+            int1_4d: false,
+            int2_4d: true,
+        };
+        let reg = ControlRegister5A::new().with_latch_config(config);
+        assert_eq!(reg.into_bits(), 0b0000_1001);
+        assert_eq!(reg.latch_config(), config);
+    }
+
+    #[test]
+    fn extract_fields() {
+        let ctrl1 = ControlRegister1A::new().with_output_data_rate(AccelOdr::Hz200);
+        let ctrl4 = ControlRegister4A::new().with_full_scale(Sensitivity::G12);
+        assert_eq!(AccelOdr::from(ctrl1), AccelOdr::Hz200);
+        assert_eq!(Sensitivity::from(ctrl4), Sensitivity::G12);
+    }
+
+    #[test]
+    fn write_tuple() {
+        let reg = ControlRegister1A::new().with_output_data_rate(AccelOdr::Hz50);
+        assert_eq!(reg.write_tuple(), (0x19, 0x20, 0b0100_0111));
+    }
+
+    #[test]
+    fn commands() {
+        let reg = ControlRegister4A::new().with_full_scale(Sensitivity::G4);
+        assert_eq!(ControlRegister4A::read_command(false), [0x23]);
+        assert_eq!(ControlRegister4A::read_command(true), [0xA3]);
+        assert_eq!(reg.write_command(), [0x23, 0b0010_0000]);
+    }
+
+    #[test]
+    fn debug_with_raw() {
+        extern crate std;
+        use std::format;
+
+        let reg = ControlRegister1A::new().with_output_data_rate(AccelOdr::Hz100);
+        let formatted = format!("{:?}", reg.debug_with_raw());
+        assert!(formatted.contains("output_data_rate"));
+        assert!(formatted.ends_with("(raw=0x57)"));
+    }
+
+    #[test]
+    fn interrupt_polarity() {
+        let reg = ControlRegister6A::active_low_interrupts(true);
+        assert_eq!(reg.into_bits(), 0b0000_0010);
+        assert!(reg.is_active_low());
+
+        let reg = ControlRegister6A::from_bits(0xFF).with_active_low(false);
+        assert_eq!(reg.into_bits(), 0b1111_1101);
+        assert!(!reg.is_active_low());
+    }
+
+    #[test]
+    fn hash_map_key() {
+        let mut cache = heapless::FnvIndexMap::<ControlRegister1A, u8, 4>::new();
+        let slow = ControlRegister1A::new().with_output_data_rate(AccelOdr::Hz10);
+        let fast = ControlRegister1A::new().with_output_data_rate(AccelOdr::Hz400);
+        cache.insert(slow, 1).unwrap();
+        cache.insert(fast, 2).unwrap();
+        cache.insert(slow, 3).unwrap();
+
+        assert_eq!(cache.len(), 2);
+        assert_eq!(cache.get(&slow), Some(&3));
+        assert_eq!(cache.get(&fast), Some(&2));
+    }
+
+    #[test]
+    fn out_burst_constants() {
+        assert_eq!(ACCEL_OUT_START, 0x28);
+        assert_eq!(ACCEL_OUT_LEN, 6);
+    }
+
+    #[test]
+    fn tap_presets() {
+        let single = ClickConfigurationRegisterA::single_tap(Axes::Z);
+        assert_eq!(single, ClickConfigurationRegisterA::new().with_zs(true));
+        assert_eq!(single.into_bits(), 0b0001_0000);
+
+        let double = ClickConfigurationRegisterA::double_tap(Axes::ALL);
+        assert_eq!(double.into_bits(), 0b0010_1010);
+    }
+
+    #[test]
+    fn axes_set_operations() {
+        let xz = Axes::X | Axes::Z;
+        assert_eq!(xz.bits(), 0b101);
+        assert!(xz.contains(Axes::X) && xz.contains(Axes::Z));
+        assert!(!xz.contains(Axes::Y));
+        assert!(Axes::ALL.contains(xz));
+
+        assert_eq!(xz & (Axes::Y | Axes::Z), Axes::Z);
+        assert!((xz & Axes::Y).is_empty());
+        assert_eq!(!xz, Axes::Y);
+        assert_eq!(!Axes::ALL, Axes::NONE);
+    }
+
+    #[test]
+    fn register_masks() {
+        assert_eq!(ControlRegister4A::WRITABLE_MASK, 0xFF);
+        assert_eq!(ControlRegister4A::RESERVED_MASK, 0x00);
+        assert_eq!(ControlRegister5A::WRITABLE_MASK, 0b1100_1111);
+        assert_eq!(ControlRegister5A::RESERVED_MASK, 0b0011_0000);
+    }
+
+    #[test]
+    fn writable_bits_round_trip() {
+        macro_rules! round_trip {
+            ($($type:ident),+ $(,)?) => {$(
+                for b in 0..=u8::MAX {
+                    assert_eq!(
+                        $type::from_bits(b).into_bits() & $type::WRITABLE_MASK,
+                        b & $type::WRITABLE_MASK,
+                        concat!(stringify!($type), " does not round-trip {:#04X}"),
+                        b
+                    );
+                }
+            )+};
+        }
+
+        round_trip!(
+            ControlRegister1A,
+            ControlRegister2A,
+            ControlRegister3A,
+            ControlRegister4A,
+            ControlRegister5A,
+            ControlRegister6A,
+            ReferenceRegisterA,
+            FifoControlRegisterA,
+            Int1ConfigurationRegisterA,
+            Int1ThresholdRegisterA,
+            Int1DurationRegisterA,
+            Int2ConfigurationRegisterA,
+            Int2ThresholdRegisterA,
+            Int2DurationRegisterA,
+            ClickConfigurationRegisterA,
+            ClickThresholdRegisterA,
+            ClickTimeLimitRegisterA,
+            ClickTimeLatencyRegisterA,
+            ClickTimeWindowRegisterA,
+        );
+    }
+
+    #[test]
+    fn as_byte() {
+        let reg = ControlRegister1A::new();
+        assert_eq!(reg.as_byte(), reg.into_bits());
+        assert_eq!(reg.as_byte(), 0x07);
+    }
+
+    #[test]
+    fn byte_ref() {
+        fn first_byte<T: AsRef<u8>>(value: &T) -> u8 {
+            *value.as_ref()
+        }
+
+        let reg = ControlRegister1A::new().with_output_data_rate(AccelOdr::Hz100);
+        assert_eq!(first_byte(&reg), 0x57);
+    }
+
+    #[test]
+    fn compare_to_byte() {
+        let reg = ControlRegister1A::new().with_output_data_rate(AccelOdr::Hz100);
+        assert_eq!(reg, 0x57);
+        assert_ne!(reg, 0x07);
+    }
+
+    #[test]
+    fn block_data_update() {
+        assert_eq!(ControlRegister4A::bdu(true).into_bits(), 0b1000_0000);
+        assert_eq!(ControlRegister4A::bdu(false).into_bits(), 0);
+    }
+
+    #[test]
+    fn auto_ranging() {
+        let mut range = Sensitivity::G1;
+        let mut steps = 0;
+        while let Some(coarser) = range.coarser() {
+            range = coarser;
+            steps += 1;
+        }
+        assert_eq!((range, steps), (Sensitivity::G12, 3));
+        assert_eq!(Sensitivity::G12.finer(), Some(Sensitivity::G4));
+        assert_eq!(Sensitivity::G1.finer(), None);
+    }
+
+    #[test]
+    fn odr_code_round_trip() {
+        for code in 0..=0b1001 {
+            let odr = AccelOdr::try_from(code).unwrap();
+            assert_eq!(u8::from(odr), code);
+        }
+        assert_eq!(
+            AccelOdr::try_from(0b1010),
+            Err(RegisterError::ValueOutOfRange)
+        );
+        assert_eq!(
+            SelfTest::try_from(0b11),
+            Err(RegisterError::ValueOutOfRange)
+        );
+    }
+
+    #[test]
+    fn from_parts() {
+        let ctrl1 = ControlRegister1A::from_parts(AccelOdr::Hz50, Axes::X | Axes::Z, true);
+        let expected = ControlRegister1A::new()
+            .with_output_data_rate(AccelOdr::Hz50)
+            .with_low_power_enable(true)
+            .with_y_enable(false);
+        assert_eq!(ctrl1, expected);
+
+        let ctrl4 = ControlRegister4A::from_parts(Sensitivity::G4, true, true);
+        assert_eq!(ctrl4, 0b1010_1000);
+    }
+
+    #[test]
+    fn raw_bits() {
+        let read_back = ControlRegister5A::from_bits(0b0011_0000);
+        let rebuilt = ControlRegister5A::new().with_fifo_enable(read_back.fifo_enable());
+        assert_eq!(rebuilt, 0);
+
+        let merged = rebuilt
+            .with_raw_bits(read_back.into_bits() & ControlRegister5A::RESERVED_MASK)
+            .with_fifo_enable(true);
+        assert_eq!(merged, 0b0111_0000);
+
+        let ctrl4 = ControlRegister4A::bdu(true).with_raw_bits(0b0000_0001);
+        assert_eq!(ctrl4, 0b1000_0001);
+    }
+
+    #[test]
+    fn odr_ticks() {
+        use core::time::Duration;
+
+        let ticks = OdrTicks(20);
+        assert_eq!(
+            ticks.to_duration(AccelOdr::Hz100),
+            Duration::from_millis(200)
+        );
+        assert_eq!(
+            OdrTicks::from_duration(Duration::from_millis(200), AccelOdr::Hz100),
+            ticks
+        );
+        assert_eq!(
+            OdrTicks::from_duration(Duration::from_secs(10), AccelOdr::Hz100),
+            OdrTicks(255)
+        );
+
+        assert_eq!(ClickTimeLimitRegisterA::from_ticks(OdrTicks(200)), 0x7F);
+        assert_eq!(ClickTimeWindowRegisterA::from_ticks(OdrTicks(200)), 200);
+    }
+
+    #[test]
+    fn register_access() {
+        assert!(RegisterAddress::CTRL_REG1_A.is_writable());
+        assert!(!RegisterAddress::STATUS_REG_A.is_writable());
+        assert!(!RegisterAddress::OUT_X_L_A.is_writable());
+        assert!(RegisterAddress::STATUS_REG_A.is_readable());
+    }
+
+    #[test]
+    fn sensitivity_scale() {
+        let scale = SensitivityScale(Sensitivity::G4);
+        assert_eq!(scale.reading_g_per_lsb(), 4.0 / 16384.0);
+        assert_eq!(scale.threshold_g_per_lsb(), 0.0625);
+        assert_eq!(scale.ths_for_g(0.5), 8);
+        assert_eq!(scale.ths_for_g(0.53), 8);
+        assert_eq!(scale.ths_for_g(100.0), 127);
+        assert_eq!(scale.ths_for_g(-1.0), 0);
+        assert_eq!(scale.g_for_ths(8), 0.5);
+        assert_eq!(scale.g_for_ths(0x80 | 8), 0.5);
+    }
+
+    #[test]
+    fn undefined_data_rate_does_not_panic() {
+        let value = ControlRegister1A::from_bits(0xF7);
+        assert_eq!(value.output_data_rate(), AccelOdr::Disabled);
+        assert!(AccelOdr::try_from(0b1010).is_err());
+    }
+
+    #[test]
+    fn register_bank_axes() {
+        let bytes = [0x0F, 0x80, 0x10, 0x00, 0xF0, 0xFF, 0x00];
+        let bank = AccelRegisterBank::new(RegisterAddress::OUT_X_L_A, &bytes).unwrap();
+        assert_eq!(bank.read(AccelAxis::X), -0x7FF1);
+        assert_eq!(bank.read(AccelAxis::Y), 0x0010);
+        assert_eq!(bank.read(AccelAxis::Z), -0x0010);
+        assert_eq!(bank.get(RegisterAddress::FIFO_CTRL_REG_A), Some(0x00));
+        assert_eq!(bank.get(RegisterAddress::STATUS_REG_A), None);
+        assert_eq!(bank.get(RegisterAddress::FIFO_SRC_REG_A), None);
+
+        assert_eq!(
+            AccelRegisterBank::new(RegisterAddress::TIME_WINDOW_A, &[0, 0]),
+            Err(RegisterError::InvalidLength {
+                expected: 1,
+                actual: 2
+            })
+        );
+    }
+
+    #[test]
+    #[should_panic(expected = "OUT_Z_H_A")]
+    fn register_bank_missing_axis() {
+        let bank = AccelRegisterBank::new(RegisterAddress::OUT_X_L_A, &[0; 5]).unwrap();
+        bank.read(AccelAxis::Z);
+    }
+
+    #[test]
+    fn int1_routing() {
+        let reg =
+            ControlRegister3A::new().with_routing(Int1Routing::DRDY1 | Int1Routing::WATERMARK);
+        assert_eq!(reg.into_bits(), 0b0001_0100);
+        assert!(reg.i1drdy1());
+        assert!(reg.i1wtm());
+        assert!(!reg.i1click());
+        assert_eq!(reg.routing(), Int1Routing::DRDY1 | Int1Routing::WATERMARK);
+
+        let reg = ControlRegister3A::from_bits(0xFF).with_routing(Int1Routing::NONE);
+        assert_eq!(reg.into_bits(), 0x01);
+    }
+
+    #[test]
+    fn int2_routing() {
+        let reg = ControlRegister6A::active_low_interrupts(true).with_routing(Int2Routing::CLICK);
+        assert_eq!(reg.into_bits(), 0b1000_0010);
+        assert!(reg.i2click_en());
+        assert!(reg.is_active_low());
+        assert_eq!(reg.routing(), Int2Routing::CLICK);
+
+        let reg = reg.with_routing(Int2Routing::NONE);
+        assert_eq!(reg.into_bits(), 0b0000_0010);
+    }
+
+    #[test]
+    fn reading_from_high_bytes() {
+        let reading = AccelReading::from_high_bytes(0x7F, 0xFF, 0x80);
+        assert_eq!(reading, AccelReading::new(127, -1, -128));
+
+        let reading = AccelReading::from_high_bytes(0x00, 0xC0, 0x40);
+        assert_eq!(reading, AccelReading::new(0, -64, 64));
+    }
+
+    #[test]
+    fn sort_register_addresses() {
+        let mut addresses = [
+            RegisterAddress::FIFO_CTRL_REG_A,
+            RegisterAddress::CTRL_REG4_A,
+            RegisterAddress::TIME_WINDOW_A,
+            RegisterAddress::CTRL_REG1_A,
+        ];
+        addresses.sort_unstable();
+        assert_eq!(addresses.map(|a| a.addr()), [0x20, 0x23, 0x2E, 0x3D]);
+        assert!(RegisterAddress::CTRL_REG1_A < RegisterAddress::CTRL_REG2_A);
+    }
+
+    #[test]
+    fn map_default() {
+        let reg = ControlRegister1A::default().map(|r| r.with_output_data_rate(AccelOdr::Hz50));
+        assert_eq!(reg.output_data_rate(), AccelOdr::Hz50);
+        assert!(reg.x_enable() && reg.y_enable() && reg.z_enable());
+    }
+
+    #[test]
+    fn reference_in_g() {
+        let reg = ReferenceRegisterA::from_g(1.0, Sensitivity::G2);
+        assert_eq!(reg.reference(), 0x20);
+        assert_eq!(reg.reference_g(Sensitivity::G2), 1.0);
+
+        let reg = ReferenceRegisterA::from_g(-1.0, Sensitivity::G2);
+        assert_eq!(reg.reference(), 0xE0);
+        assert_eq!(reg.reference_g(Sensitivity::G2), -1.0);
+
+        assert_eq!(
+            ReferenceRegisterA::from_g(10.0, Sensitivity::G2).reference(),
+            0x7F
+        );
+        assert_eq!(
+            ReferenceRegisterA::from_g(-10.0, Sensitivity::G2).reference(),
+            0x80
+        );
+    }
+
+    #[test]
+    fn bandwidth() {
+        let reg = ControlRegister1A::new().with_output_data_rate(AccelOdr::Hz100);
+        assert_eq!(reg.bandwidth_hz(), 50.0);
+        let reg = reg.with_output_data_rate(AccelOdr::Hz400);
+        assert_eq!(reg.bandwidth_hz(), 200.0);
+        let reg = reg.with_output_data_rate(AccelOdr::LpHz1620NormalHz5376);
+        assert_eq!(reg.bandwidth_hz(), 672.0);
+        assert_eq!(reg.with_low_power_enable(true).bandwidth_hz(), 2688.0);
+        assert_eq!(ControlRegister1A::from_bits(0x00).bandwidth_hz(), 0.0);
+    }
+
+    #[test]
+    fn odr_lookup_table() {
+        assert_eq!(ACCEL_ODR_HZ[AccelOdr::Hz400 as usize], 400.0);
+        assert_eq!(
+            ACCEL_ODR_HZ[AccelOdr::LpHz1620NormalHz5376 as usize],
+            1344.0
+        );
+        for code in 0..=9 {
+            let odr = AccelOdr::try_from(code).unwrap();
+            assert_eq!(odr.frequency_hz(), ACCEL_ODR_HZ[code as usize]);
+        }
+    }
+
+    #[test]
+    fn register_bank_index() {
+        let bytes = [0x47, 0x00, 0x00, 0x08, 0x00, 0x00, 0x00, 0x0F];
+        let bank = AccelRegisterBank::new(RegisterAddress::CTRL_REG1_A, &bytes).unwrap();
+        assert_eq!(bank[RegisterAddress::CTRL_REG1_A], 0x47);
+        assert_eq!(bank[RegisterAddress::CTRL_REG4_A], 0x08);
+        assert_eq!(bank[RegisterAddress::STATUS_REG_A], 0x0F);
+    }
+
+    #[test]
+    #[should_panic(expected = "register OUT_X_L_A was not captured")]
+    fn register_bank_index_out_of_range() {
+        let bank = AccelRegisterBank::new(RegisterAddress::CTRL_REG1_A, &[0; 8]).unwrap();
+        let _ = bank[RegisterAddress::OUT_X_L_A];
+    }
+
+    #[test]
+    fn fifo_trigger_source() {
+        let reg = FifoControlRegisterA::new().with_fifo_mode(FifoMode::Trigger);
+        assert_eq!(reg.trigger_source(), TriggerSource::Int1);
+
+        let reg = reg.with_trigger_source(TriggerSource::Int2);
+        assert!(reg.trigger_on_int2());
+        assert_eq!(reg.trigger_source(), TriggerSource::Int2);
+        assert_eq!(reg.into_bits(), 0b1110_0000);
+    }
+
+    #[test]
+    fn checked_seven_bit_setters() {
+        let reg = Int1ThresholdRegisterA::new()
+            .try_with_threshold(127)
+            .unwrap();
+        assert_eq!(reg.into_bits(), 0x7F);
+        assert_eq!(
+            Int1ThresholdRegisterA::new().try_with_threshold(128),
+            Err(RegisterError::ValueOutOfRange)
+        );
+        assert!(ClickTimeLimitRegisterA::new()
+            .try_with_time_limit(0xFF)
+            .is_err());
+    }
+
+    #[test]
+    fn sample_rate() {
+        let normal = ControlRegister1A::new().with_output_data_rate(AccelOdr::LpHz1620NormalHz5376);
+        assert_eq!(normal.sample_rate_hz(), Some(1344.0));
+        assert_eq!(
+            normal.with_low_power_enable(true).sample_rate_hz(),
+            Some(5376.0)
+        );
+
+        let normal = normal.with_output_data_rate(AccelOdr::LpHz1620);
+        assert_eq!(normal.sample_rate_hz(), None);
+        assert_eq!(normal.bandwidth_hz(), 0.0);
+        assert_eq!(
+            normal.with_low_power_enable(true).sample_rate_hz(),
+            Some(1620.0)
+        );
+
+        assert_eq!(ControlRegister1A::from_bits(0x00).sample_rate_hz(), None);
+        assert_eq!(ControlRegister1A::new().sample_rate_hz(), None);
+    }
+
+    #[test]
+    fn wake_on_motion_config() {
+        let config = wake_on_motion(0.25, OdrTicks(2), Sensitivity::G1, Axes::ALL);
+        assert_eq!(config.int1_ths.threshold(), 16);
+        assert_eq!(config.int1_duration.duration(), 2);
+        assert_eq!(config.int1_cfg.into_bits(), 0b0010_1010);
+        assert_eq!(config.ctrl3.routing(), Int1Routing::AOI1);
+        assert!(config.ctrl5.latch_config().int1_latched);
+    }
+
+    #[test]
+    fn odr_power_modes() {
+        for code in 0..=9 {
+            let odr = AccelOdr::try_from(code).unwrap();
+            assert_eq!(odr.is_low_power_only(), odr == AccelOdr::LpHz1620);
+            assert!(!odr.requires_normal_mode());
+        }
+    }
+
+    #[test]
+    fn enabled_axes() {
+        let reg = ControlRegister1A::from_parts(AccelOdr::Hz50, Axes::X | Axes::Z, false);
+        assert!(reg.axis_enabled(AccelAxis::X));
+        assert!(!reg.axis_enabled(AccelAxis::Y));
+        assert!(reg.axis_enabled(AccelAxis::Z));
+        assert_eq!(reg.enabled_count(), 2);
+        assert_eq!(ControlRegister1A::new().enabled_count(), 3);
+    }
+
+    #[test]
+    fn por_values_match_defaults() {
+        macro_rules! assert_por {
+            ($($type:ident),+ $(,)?) => {$(
+                assert_eq!(
+                    $type::new().into_bits(),
+                    $type::POR_VALUE,
+                    concat!(stringify!($type), " defaults differ from the datasheet reset value")
+                );
+            )+};
+        }
+
+        assert_por!(
+            ControlRegister1A,
+            ControlRegister2A,
+            ControlRegister3A,
+            ControlRegister4A,
+            ControlRegister5A,
+            ControlRegister6A,
+            ReferenceRegisterA,
+            StatusRegisterA,
+            OutXLowA,
+            OutXHighA,
+            OutYLowA,
+            OutYHighA,
+            OutZLowA,
+            OutZHighA,
+            FifoControlRegisterA,
+            FifoSourceRegisterA,
+            Int1ConfigurationRegisterA,
+            Int1SourceRegisterA,
+            Int1ThresholdRegisterA,
+            Int1DurationRegisterA,
+            Int2ConfigurationRegisterA,
+            Int2SourceRegisterA,
+            Int2ThresholdRegisterA,
+            Int2DurationRegisterA,
+            ClickConfigurationRegisterA,
+            ClickSourceRegisterA,
+            ClickThresholdRegisterA,
+            ClickTimeLimitRegisterA,
+            ClickTimeLatencyRegisterA,
+            ClickTimeWindowRegisterA,
+        );
+    }
+
+    #[test]
+    fn self_test() {
+        let reg = ControlRegister4A::new().with_self_test(SelfTest::Mode0);
+        assert_eq!(reg.into_bits(), 0b0000_0010);
+        assert_eq!(reg.self_test(), SelfTest::Mode0);
+
+        let reg = reg.with_self_test(SelfTest::Mode1);
+        assert_eq!(reg.into_bits(), 0b0000_0100);
+        assert_eq!(reg.self_test(), SelfTest::Mode1);
+    }
+
+    #[test]
+    fn fifo_configure() {
+        let reg = FifoControlRegisterA::configure(FifoMode::Stream, 16, false).unwrap();
+        assert_eq!(reg.into_bits(), 0b1001_0000);
+        assert_eq!(
+            FifoControlRegisterA::configure(FifoMode::Bypass, 1, false),
+            Err(RegisterError::ValueOutOfRange)
+        );
+        assert_eq!(
+            FifoControlRegisterA::configure(FifoMode::FIFO, 32, false),
+            Err(RegisterError::ValueOutOfRange)
+        );
+    }
+
+    #[test]
+    fn fifo_depth_partial() {
+        let src = FifoSourceRegisterA::from_bits(0b0000_1010);
+        assert_eq!(fifo_depth(src), 10);
+    }
+
+    #[test]
+    fn fifo_depth_overrun() {
+        let src = FifoSourceRegisterA::from_bits(0b1101_1111);
+        assert_eq!(fifo_depth(src), 32);
+    }
+
+    #[test]
+    fn reading_array_round_trip() {
+        let reading = AccelReading::new(1, -2, 3);
+        let array: [i16; 3] = reading.into();
+        assert_eq!(array, [1, -2, 3]);
+        assert_eq!(AccelReading::from(array), reading);
+    }
+
+    #[test]
+    fn reading_from_slice() {
+        let bytes = [0x01, 0x00, 0xFE, 0xFF, 0x00, 0x40, 0x00];
+        assert_eq!(
+            AccelReading::try_from(&bytes[..6]),
+            Ok(AccelReading::new(1, -2, 0x4000))
+        );
+        assert_eq!(
+            AccelReading::try_from(&bytes[..5]),
+            Err(RegisterError::InvalidLength {
+                expected: 6,
+                actual: 5
+            })
+        );
+        assert_eq!(
+            AccelReading::try_from(&bytes[..]),
+            Err(RegisterError::InvalidLength {
+                expected: 6,
+                actual: 7
+            })
+        );
+    }
+
+    #[test]
+    fn fifo_burst() {
+        let bytes = [
+            0x01, 0x00, 0x02, 0x00, 0x03, 0x00, 0xFF, 0xFF, 0xFE, 0xFF, 0xFD, 0xFF, 0xAA,
+        ];
+        let expected = [AccelReading::new(1, 2, 3), AccelReading::new(-1, -2, -3)];
+        assert!(fifo_readings(&bytes[..12]).eq(expected));
+        assert!(fifo_readings(&bytes).eq(expected));
+    }
+
+    #[test]
+    fn reading_saturation() {
+        let reading = AccelReading::new(1000, -1000, 16000);
+        assert!(!reading.is_saturated(false, true));
+        assert!(!reading.is_saturated(false, false));
+        assert!(!reading.is_saturated(true, false));
+
+        // High-resolution (12-bit) mode.
+        assert!(AccelReading::new(0, 0x7FF0, 0).is_saturated(false, true));
+        assert!(!AccelReading::new(0, 0x7FE0, 0).is_saturated(false, true));
+
+        // Normal (10-bit) mode.
+        assert!(AccelReading::new(0, 0, 0x7FC0).is_saturated(false, false));
+        assert!(!AccelReading::new(0, 0, 0x7F80).is_saturated(false, false));
+
+        // Low-power (8-bit) mode, regardless of the HR bit.
+        assert!(AccelReading::new(0x7F00, 0, 0).is_saturated(true, false));
+        assert!(AccelReading::new(0x7F00, 0, 0).is_saturated(true, true));
+        assert!(!AccelReading::new(0x7E00, 0, 0).is_saturated(true, false));
+
+        for (low_power, high_resolution) in [(true, false), (false, false), (false, true)] {
+            assert!(AccelReading::new(i16::MIN, 0, 0).is_saturated(low_power, high_resolution));
+        }
+    }
+
+    #[test]
+    fn reading_in_g() {
+        extern crate std;
+        use std::string::ToString;
+
+        let reading = AccelReading::new(0x4000, -0x2000, 0).to_g(Sensitivity::G1);
+        assert_eq!(reading, AccelG([1.0, -0.5, 0.0]));
+        assert_eq!(reading.to_string(), "x=1.00g y=-0.50g z=0.00g");
+    }
+
+    #[cfg(feature = "defmt")]
+    #[test]
+    fn reading_in_g_defmt() {
+        fn assert_format<T: defmt::Format>() {}
+        assert_format::<AccelG>();
+        assert_format::<crate::mag::MagGauss>();
+    }
+
+    #[test]
+    fn status_axis_flags() {
+        let status = StatusRegisterA::from_bits(0b0010_1101);
+        assert_eq!(
+            status.data_ready(),
+            AxisFlags {
+                x: true,
+                y: false,
+                z: true,
+                all: true
+            }
+        );
+        assert_eq!(
+            status.overrun(),
+            AxisFlags {
+                x: false,
+                y: true,
+                z: false,
+                all: false
+            }
+        );
+    }
 }
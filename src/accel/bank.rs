@@ -0,0 +1,93 @@
+use crate::accel::*;
+use crate::{assemble_i16, RegisterError};
+use core::ops::Index;
+
+/// The number of accelerometer registers, from [`CTRL_REG1_A`](RegisterAddress::CTRL_REG1_A)
+/// through [`TIME_WINDOW_A`](RegisterAddress::TIME_WINDOW_A).
+const BANK_CAPACITY: usize = 0x3E - 0x20;
+
+/// A snapshot of a contiguous range of accelerometer registers, e.g. as captured by a burst read.
+///
+/// ```
+/// # use lsm303dlhc_registers::accel::{AccelAxis, AccelRegisterBank, RegisterAddress};
+/// let bytes = [0x00, 0x40, 0x00, 0xC0, 0x10, 0x00];
+/// let bank = AccelRegisterBank::new(RegisterAddress::OUT_X_L_A, &bytes).unwrap();
+/// assert_eq!(bank.read(AccelAxis::X), 0x4000);
+/// assert_eq!(bank.read(AccelAxis::Y), -0x4000);
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+pub struct AccelRegisterBank {
+    start: u8,
+    len: usize,
+    bytes: [u8; BANK_CAPACITY],
+}
+
+impl AccelRegisterBank {
+    /// Captures the bytes read starting at the `start` register.
+    ///
+    /// Fails with [`RegisterError::InvalidLength`] if the bytes extend beyond the
+    /// last accelerometer register.
+    pub fn new(start: RegisterAddress, bytes: &[u8]) -> Result<Self, RegisterError> {
+        let offset = (start.addr() - RegisterAddress::CTRL_REG1_A.addr()) as usize;
+        let available = BANK_CAPACITY - offset;
+        if bytes.len() > available {
+            return Err(RegisterError::InvalidLength {
+                expected: available,
+                actual: bytes.len(),
+            });
+        }
+
+        let mut bank = Self {
+            start: start.addr(),
+            len: bytes.len(),
+            bytes: [0; BANK_CAPACITY],
+        };
+        bank.bytes[..bytes.len()].copy_from_slice(bytes);
+        Ok(bank)
+    }
+
+    /// Returns the address of the first captured register.
+    pub const fn start(&self) -> u8 {
+        self.start
+    }
+
+    /// Returns the value of a register, or `None` if it was not captured.
+    pub fn get(&self, address: RegisterAddress) -> Option<u8> {
+        let index = address.addr().checked_sub(self.start)? as usize;
+        if index < self.len {
+            Some(self.bytes[index])
+        } else {
+            None
+        }
+    }
+
+    /// Returns the value of an axis, assembled from its low and high output registers.
+    ///
+    /// # Panics
+    ///
+    /// Panics if either output register of the axis was not captured.
+    pub fn read(&self, axis: AccelAxis) -> i16 {
+        assemble_i16(self[axis.low_register()], self[axis.high_register()])
+    }
+}
+
+/// Returns the value of a captured register.
+///
+/// # Panics
+///
+/// Panics if the register is outside the captured range; see [`AccelRegisterBank::get`].
+impl Index<RegisterAddress> for AccelRegisterBank {
+    type Output = u8;
+
+    fn index(&self, address: RegisterAddress) -> &Self::Output {
+        let index = address.addr().wrapping_sub(self.start) as usize;
+        match self.bytes[..self.len].get(index) {
+            Some(value) => value,
+            None => panic!(
+                "register {} was not captured by the register bank",
+                address.name()
+            ),
+        }
+    }
+}
@@ -1,11 +1,158 @@
 use crate::accel::*;
+use crate::{assemble_i16, RegisterError};
 use core::ops::Add;
 
+/// A raw accelerometer reading, assembled from the `OUT_*_A` registers.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+pub struct AccelReading {
+    /// The X-axis value.
+    pub x: i16,
+    /// The Y-axis value.
+    pub y: i16,
+    /// The Z-axis value.
+    pub z: i16,
+}
+
+impl AccelReading {
+    /// Creates a new reading from the axis values.
+    pub const fn new(x: i16, y: i16, z: i16) -> Self {
+        Self { x, y, z }
+    }
+
+    /// Creates a reading from the `OUT_*_H_A` registers only, sign-extending each byte.
+    ///
+    /// Use this in low-power mode, where only the high bytes carry data and the
+    /// `OUT_*_L_A` registers are undefined; otherwise assemble the reading from all
+    /// six output bytes via `TryFrom<&[u8]>`.
+    ///
+    /// Unlike the six-byte conversion, the values are not left-justified: 1 LSB is
+    /// full-scale / 128, see [`SensitivityScale::threshold_g_per_lsb`].
+    pub const fn from_high_bytes(x_h: u8, y_h: u8, z_h: u8) -> Self {
+        Self::new(x_h as i8 as i16, y_h as i8 as i16, z_h as i8 as i16)
+    }
+
+    /// Determines whether any axis is clipped at the end of the measurement range.
+    ///
+    /// The values are left-justified, so the largest positive value depends on the
+    /// resolution selected by `low_power` (`LPen` in [`ControlRegister1A`]) and
+    /// `high_resolution` (`HR` in [`ControlRegister4A`]):
+    ///
+    /// * low-power (8-bit) mode: `0x7F00`; this takes precedence over `high_resolution`,
+    /// * normal (10-bit) mode: `0x7FC0`,
+    /// * high-resolution (12-bit) mode: `0x7FF0`.
+    ///
+    /// The smallest value is `i16::MIN` in every mode.
+    pub const fn is_saturated(&self, low_power: bool, high_resolution: bool) -> bool {
+        let max = if low_power {
+            0x7F00
+        } else if high_resolution {
+            0x7FF0
+        } else {
+            0x7FC0
+        };
+        const fn saturated(value: i16, max: i16) -> bool {
+            value >= max || value == i16::MIN
+        }
+        saturated(self.x, max) || saturated(self.y, max) || saturated(self.z, max)
+    }
+
+    /// Converts the reading to g, using the full scale the reading was taken with.
+    pub fn to_g(&self, sensitivity: Sensitivity) -> AccelG {
+        let g_per_lsb = SensitivityScale(sensitivity).reading_g_per_lsb();
+        AccelG([
+            self.x as f32 * g_per_lsb,
+            self.y as f32 * g_per_lsb,
+            self.z as f32 * g_per_lsb,
+        ])
+    }
+}
+
+/// An acceleration in g, in X-Y-Z order; see [`AccelReading::to_g`].
+///
+/// Formats as e.g. `x=0.98g y=0.01g z=0.02g`, including when logging via `defmt`.
+#[derive(Debug, Default, Clone, Copy, PartialEq)]
+pub struct AccelG(pub [f32; 3]);
+
+impl core::fmt::Display for AccelG {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        let [x, y, z] = self.0;
+        write!(f, "x={x:.2}g y={y:.2}g z={z:.2}g")
+    }
+}
+
+#[cfg(feature = "defmt")]
+impl defmt::Format for AccelG {
+    fn format(&self, f: defmt::Formatter) {
+        let [x, y, z] = self.0;
+        defmt::write!(f, "x={=f32}g y={=f32}g z={=f32}g", x, y, z);
+    }
+}
+
+impl From<AccelReading> for [i16; 3] {
+    fn from(value: AccelReading) -> Self {
+        [value.x, value.y, value.z]
+    }
+}
+
+impl From<[i16; 3]> for AccelReading {
+    fn from(value: [i16; 3]) -> Self {
+        Self::new(value[0], value[1], value[2])
+    }
+}
+
+/// Assembles a reading from the six bytes read from [`OUT_X_L_A`](RegisterAddress::OUT_X_L_A)
+/// through [`OUT_Z_H_A`](RegisterAddress::OUT_Z_H_A) in little endian order.
+impl TryFrom<&[u8]> for AccelReading {
+    type Error = RegisterError;
+
+    fn try_from(bytes: &[u8]) -> Result<Self, Self::Error> {
+        match *bytes {
+            [xl, xh, yl, yh, zl, zh] => Ok(Self::new(
+                assemble_i16(xl, xh),
+                assemble_i16(yl, yh),
+                assemble_i16(zl, zh),
+            )),
+            _ => Err(RegisterError::InvalidLength {
+                expected: 6,
+                actual: bytes.len(),
+            }),
+        }
+    }
+}
+
+impl From<ControlRegister1A> for AccelOdr {
+    fn from(value: ControlRegister1A) -> Self {
+        value.output_data_rate()
+    }
+}
+
+impl From<ControlRegister4A> for Sensitivity {
+    fn from(value: ControlRegister4A) -> Self {
+        value.full_scale()
+    }
+}
+
+/// Decodes a FIFO burst read into one reading per six bytes.
+///
+/// The bytes are expected in the order of [`OUT_X_L_A`](RegisterAddress::OUT_X_L_A) through
+/// [`OUT_Z_H_A`](RegisterAddress::OUT_Z_H_A), repeated for every FIFO level. A trailing
+/// partial reading is ignored.
+pub fn fifo_readings(buf: &[u8]) -> impl Iterator<Item = AccelReading> + '_ {
+    buf.chunks_exact(ACCEL_OUT_LEN).map(|chunk| {
+        AccelReading::new(
+            assemble_i16(chunk[0], chunk[1]),
+            assemble_i16(chunk[2], chunk[3]),
+            assemble_i16(chunk[4], chunk[5]),
+        )
+    })
+}
+
 impl Add<OutXHighA> for OutXLowA {
     type Output = i16;
 
     fn add(self, hi: OutXHighA) -> Self::Output {
-        (hi.bits() as i16) << 8 | (self.bits() as i16)
+        assemble_i16(self.bits(), hi.bits())
     }
 }
 
@@ -21,7 +168,7 @@ impl Add<OutYHighA> for OutYLowA {
     type Output = i16;
 
     fn add(self, hi: OutYHighA) -> Self::Output {
-        (hi.bits() as i16) << 8 | (self.bits() as i16)
+        assemble_i16(self.bits(), hi.bits())
     }
 }
 
@@ -37,7 +184,7 @@ impl Add<OutZHighA> for OutZLowA {
     type Output = i16;
 
     fn add(self, hi: OutZHighA) -> Self::Output {
-        (hi.bits() as i16) << 8 | (self.bits() as i16)
+        assemble_i16(self.bits(), hi.bits())
     }
 }
 
@@ -1,9 +1,113 @@
 //! Types used in the accelerometer registers.
 
+/// Implements the set operations for a flag type wrapping an `u8`
+/// that provides an `ALL` constant of all valid flags.
+macro_rules! flag_ops {
+    ($type:ident) => {
+        impl $type {
+            /// Returns the flags set in either `self` or `other`.
+            pub const fn union(self, other: Self) -> Self {
+                Self(self.0 | other.0)
+            }
+
+            /// Returns the flags set in both `self` and `other`.
+            pub const fn intersection(self, other: Self) -> Self {
+                Self(self.0 & other.0)
+            }
+
+            /// Returns all valid flags not set in `self`.
+            pub const fn complement(self) -> Self {
+                Self(!self.0 & Self::ALL.0)
+            }
+
+            /// Determines whether all flags of `other` are set in `self`.
+            pub const fn contains(self, other: Self) -> bool {
+                self.0 & other.0 == other.0
+            }
+
+            /// Determines whether no flag is set.
+            pub const fn is_empty(self) -> bool {
+                self.0 == 0
+            }
+        }
+
+        impl core::ops::BitOr for $type {
+            type Output = Self;
+
+            fn bitor(self, rhs: Self) -> Self::Output {
+                self.union(rhs)
+            }
+        }
+
+        impl core::ops::BitOrAssign for $type {
+            fn bitor_assign(&mut self, rhs: Self) {
+                *self = self.union(rhs);
+            }
+        }
+
+        impl core::ops::BitAnd for $type {
+            type Output = Self;
+
+            fn bitand(self, rhs: Self) -> Self::Output {
+                self.intersection(rhs)
+            }
+        }
+
+        impl core::ops::BitAndAssign for $type {
+            fn bitand_assign(&mut self, rhs: Self) {
+                *self = self.intersection(rhs);
+            }
+        }
+
+        impl core::ops::Not for $type {
+            type Output = Self;
+
+            fn not(self) -> Self::Output {
+                self.complement()
+            }
+        }
+    };
+}
+
 /// Accelerometer Output Data Rate
-#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+///
+/// This enum is marked `#[non_exhaustive]` so that further data rates can be added
+/// without a breaking change. Matches outside of this crate require a wildcard arm:
+///
+/// ```
+/// # use lsm303dlhc_registers::accel::AccelOdr;
+/// fn is_fast(odr: AccelOdr) -> bool {
+///     match odr {
+///         AccelOdr::Disabled | AccelOdr::Hz1 | AccelOdr::Hz10 => false,
+///         _ => true,
+///     }
+/// }
+/// assert!(is_fast(AccelOdr::Hz400));
+/// ```
+///
+/// Omitting the wildcard arm fails to compile:
+///
+/// ```compile_fail
+/// # use lsm303dlhc_registers::accel::AccelOdr;
+/// fn is_disabled(odr: AccelOdr) -> bool {
+///     match odr {
+///         AccelOdr::Disabled => true,
+///         AccelOdr::Hz1
+///         | AccelOdr::Hz10
+///         | AccelOdr::Hz25
+///         | AccelOdr::Hz50
+///         | AccelOdr::Hz100
+///         | AccelOdr::Hz200
+///         | AccelOdr::Hz400
+///         | AccelOdr::LpHz1620
+///         | AccelOdr::LpHz1620NormalHz5376 => false,
+///     }
+/// }
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
 #[cfg_attr(feature = "defmt", derive(defmt::Format))]
 #[repr(u8)]
+#[non_exhaustive]
 pub enum AccelOdr {
     /// Power-down mode (`0b0000`)
     Disabled = 0b0000,
@@ -23,7 +127,9 @@ pub enum AccelOdr {
     Hz400 = 0b0111,
     /// 1.620 kHz when in Low-Power mode (`0b1000`)
     LpHz1620 = 0b1000,
-    /// 5.376 kHz when in normal mode, 1.344 kHz when in normal mode (`0b1001`)
+    /// 1.344 kHz when in normal mode, 5.376 kHz when in Low-Power mode (`0b1001`)
+    ///
+    /// See [`ControlRegister1A::sample_rate_hz`](crate::accel::ControlRegister1A::sample_rate_hz).
     LpHz1620NormalHz5376 = 0b1001,
 }
 
@@ -45,15 +151,56 @@ impl AccelOdr {
             0b0111 => AccelOdr::Hz400,
             0b1000 => AccelOdr::LpHz1620,
             0b1001 => AccelOdr::LpHz1620NormalHz5376,
-            _ => unreachable!(),
+            // 0b1010 through 0b1111 are undefined and treated as power-down mode.
+            _ => AccelOdr::Disabled,
         }
     }
+
+    /// Returns the output data rate in Hz, or `0.0` if the sensor is powered down.
+    ///
+    /// [`LpHz1620NormalHz5376`](Self::LpHz1620NormalHz5376) is reported with its
+    /// normal-mode rate of 1.344 kHz.
+    pub const fn frequency_hz(self) -> f32 {
+        ACCEL_ODR_HZ[self as usize]
+    }
+
+    /// Determines whether the data rate is only valid in low-power mode,
+    /// i.e. [`LpHz1620`](Self::LpHz1620).
+    pub const fn is_low_power_only(self) -> bool {
+        matches!(self, AccelOdr::LpHz1620)
+    }
+
+    /// Determines whether the data rate is only valid in normal mode.
+    ///
+    /// This is never the case: every code below [`LpHz1620`](Self::LpHz1620) is valid in
+    /// both modes, and [`LpHz1620NormalHz5376`](Self::LpHz1620NormalHz5376) is valid in
+    /// low-power mode as well, where it runs at 5.376 kHz instead of 1.344 kHz.
+    /// Use [`ControlRegister1A::sample_rate_hz`](crate::accel::ControlRegister1A::sample_rate_hz)
+    /// for the rate that applies to the configured mode.
+    pub const fn requires_normal_mode(self) -> bool {
+        false
+    }
+
+    /// Returns the data rate for formatting as its frequency, e.g. `400 Hz`;
+    /// see [`frequency_hz`](Self::frequency_hz).
+    pub const fn display_hz(self) -> crate::DisplayHz {
+        crate::DisplayHz(self.frequency_hz())
+    }
 }
 
+/// The output data rates in Hz, indexed by the raw 4-bit [`AccelOdr`] code.
+///
+/// The dual-rate code `0b1001` ([`LpHz1620NormalHz5376`](AccelOdr::LpHz1620NormalHz5376))
+/// is listed with its normal-mode rate of 1.344 kHz; in low-power mode it runs at 5.376 kHz.
+pub const ACCEL_ODR_HZ: [f32; 10] = [
+    0.0, 1.0, 10.0, 25.0, 50.0, 100.0, 200.0, 400.0, 1620.0, 1344.0,
+];
+
 /// Acceleration sensitivity (full scale selection).
-#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
 #[cfg_attr(feature = "defmt", derive(defmt::Format))]
 #[repr(u8)]
+#[non_exhaustive]
 pub enum Sensitivity {
     /// Range: [-2g, +2g]. Sensitivity ~ 1 g / (1 << 14) LSB (`0b00`)
     G1 = 0b00,
@@ -80,12 +227,119 @@ impl Sensitivity {
             _ => unreachable!(),
         }
     }
+
+    /// Returns the next larger measurement range, or `None` if this is the largest one.
+    pub const fn coarser(self) -> Option<Self> {
+        match self {
+            Sensitivity::G1 => Some(Sensitivity::G2),
+            Sensitivity::G2 => Some(Sensitivity::G4),
+            Sensitivity::G4 => Some(Sensitivity::G12),
+            Sensitivity::G12 => None,
+        }
+    }
+
+    /// Returns the next smaller measurement range, or `None` if this is the smallest one.
+    pub const fn finer(self) -> Option<Self> {
+        match self {
+            Sensitivity::G1 => None,
+            Sensitivity::G2 => Some(Sensitivity::G1),
+            Sensitivity::G4 => Some(Sensitivity::G2),
+            Sensitivity::G12 => Some(Sensitivity::G4),
+        }
+    }
+}
+
+/// The scale factors of a [`Sensitivity`] for readings and for the 7-bit thresholds of
+/// the interrupt and click threshold registers.
+///
+/// Readings are left-justified 16-bit values, whereas thresholds use 1 LSB = full-scale / 128.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+pub struct SensitivityScale(pub Sensitivity);
+
+impl SensitivityScale {
+    /// Returns the g per LSB of a raw reading, see [`AccelReading::to_g`](crate::accel::AccelReading::to_g).
+    pub fn reading_g_per_lsb(self) -> f32 {
+        let g = match self.0 {
+            Sensitivity::G1 => 1.0,
+            Sensitivity::G2 => 2.0,
+            Sensitivity::G4 => 4.0,
+            Sensitivity::G12 => 12.0,
+        };
+        g / (1 << 14) as f32
+    }
+
+    /// Returns the g per LSB of a threshold register, i.e. the full scale divided by 128.
+    pub fn threshold_g_per_lsb(self) -> f32 {
+        let full_scale = match self.0 {
+            Sensitivity::G1 => 2.0,
+            Sensitivity::G2 => 4.0,
+            Sensitivity::G4 => 8.0,
+            Sensitivity::G12 => 16.0,
+        };
+        full_scale / 128.0
+    }
+
+    /// Converts an acceleration in g into the nearest threshold value, saturating at 127.
+    ///
+    /// Negative accelerations result in a threshold of zero.
+    pub fn ths_for_g(self, g: f32) -> u8 {
+        let ths = g / self.threshold_g_per_lsb() + 0.5;
+        if ths >= 127.0 {
+            127
+        } else if ths > 0.0 {
+            ths as u8
+        } else {
+            0
+        }
+    }
+
+    /// Converts a threshold value into g; the most significant bit is ignored.
+    pub fn g_for_ths(self, ths: u8) -> f32 {
+        (ths & 0x7F) as f32 * self.threshold_g_per_lsb()
+    }
+}
+
+/// Accelerometer self-test mode.
+///
+/// The LSM303DLHC datasheet requires the `ST1` and `ST0` bits of
+/// [`CTRL_REG4_A`](crate::accel::RegisterAddress::CTRL_REG4_A) to be kept at `0`, so only
+/// [`Disabled`](Self::Disabled) is a supported setting. [`Mode0`](Self::Mode0) and
+/// [`Mode1`](Self::Mode1) mirror the register layout of related ST devices and exist for
+/// decoding register values only.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+#[repr(u8)]
+pub enum SelfTest {
+    /// Normal mode (`0b00`)
+    Disabled = 0b00,
+    /// Self-test 0 (`0b01`); not supported on the LSM303DLHC.
+    Mode0 = 0b01,
+    /// Self-test 1 (`0b10`); not supported on the LSM303DLHC.
+    Mode1 = 0b10,
+}
+
+impl SelfTest {
+    /// Converts the value into an `u8`.
+    pub const fn into_bits(self) -> u8 {
+        self as u8
+    }
+
+    pub(crate) const fn from_bits(value: u8) -> Self {
+        match value {
+            0b01 => SelfTest::Mode0,
+            0b10 => SelfTest::Mode1,
+            // 0b11 is not allowed and treated as normal mode.
+            _ => SelfTest::Disabled,
+        }
+    }
 }
 
 /// FIFO mode configuration.
-#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
 #[cfg_attr(feature = "defmt", derive(defmt::Format))]
 #[repr(u8)]
+#[non_exhaustive]
 pub enum FifoMode {
     /// Bypass mode (`0b00`)
     ///
@@ -117,10 +371,21 @@ impl FifoMode {
     }
 }
 
+/// The interrupt pad whose signal triggers the FIFO in [`FifoMode::Trigger`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+pub enum TriggerSource {
+    /// Trigger event linked to the trigger signal on INT1.
+    Int1,
+    /// Trigger event linked to the trigger signal on INT2.
+    Int2,
+}
+
 /// High-Pass Filter Mode
-#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
 #[cfg_attr(feature = "defmt", derive(defmt::Format))]
 #[repr(u8)]
+#[non_exhaustive]
 pub enum HighpassFilterMode {
     /// Normal mode (`0b00`)
     ///
@@ -150,3 +415,349 @@ impl HighpassFilterMode {
         }
     }
 }
+
+/// High-pass filter cutoff frequency selection.
+///
+/// The resulting cutoff frequency depends on the output data rate,
+/// see [`HighpassCutoff::cutoff_hz`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+#[repr(u8)]
+pub enum HighpassCutoff {
+    /// Highest cutoff frequency (`0b00`)
+    Highest = 0b00,
+    /// High cutoff frequency (`0b01`)
+    High = 0b01,
+    /// Low cutoff frequency (`0b10`)
+    Low = 0b10,
+    /// Lowest cutoff frequency (`0b11`)
+    Lowest = 0b11,
+}
+
+impl HighpassCutoff {
+    /// Converts the value into an `u8`.
+    pub const fn into_bits(self) -> u8 {
+        self as u8
+    }
+
+    pub(crate) const fn from_bits(value: u8) -> Self {
+        match value {
+            0b00 => HighpassCutoff::Highest,
+            0b01 => HighpassCutoff::High,
+            0b10 => HighpassCutoff::Low,
+            0b11 => HighpassCutoff::Lowest,
+            _ => unreachable!(),
+        }
+    }
+
+    /// Returns the -3 dB cutoff frequency of the high-pass filter in Hz for the
+    /// specified output data rate.
+    ///
+    /// The values follow the cutoff frequency table of the accelerometer core
+    /// (see ST application note AN3308). For the dual-rate
+    /// [`LpHz1620NormalHz5376`](AccelOdr::LpHz1620NormalHz5376) setting the normal mode
+    /// data rate of 1.344 kHz is assumed. Returns `0.0` when the accelerometer is powered down.
+    pub fn cutoff_hz(self, odr: AccelOdr) -> f32 {
+        let table: [f32; 4] = match odr {
+            AccelOdr::Disabled => [0.0, 0.0, 0.0, 0.0],
+            AccelOdr::Hz1 => [0.02, 0.008, 0.004, 0.002],
+            AccelOdr::Hz10 => [0.2, 0.08, 0.04, 0.02],
+            AccelOdr::Hz25 => [0.5, 0.2, 0.1, 0.05],
+            AccelOdr::Hz50 => [1.0, 0.5, 0.2, 0.1],
+            AccelOdr::Hz100 => [2.0, 1.0, 0.5, 0.2],
+            AccelOdr::Hz200 => [4.0, 2.0, 1.0, 0.5],
+            AccelOdr::Hz400 => [8.0, 4.0, 2.0, 1.0],
+            AccelOdr::LpHz1620 => [32.0, 16.0, 8.0, 4.0],
+            AccelOdr::LpHz1620NormalHz5376 => [26.88, 13.44, 6.72, 3.36],
+        };
+        table[self as usize]
+    }
+}
+
+/// An accelerometer axis, selecting its pair of output registers.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+pub enum AccelAxis {
+    /// The X axis, see [`OUT_X_L_A`](crate::accel::RegisterAddress::OUT_X_L_A).
+    X,
+    /// The Y axis, see [`OUT_Y_L_A`](crate::accel::RegisterAddress::OUT_Y_L_A).
+    Y,
+    /// The Z axis, see [`OUT_Z_L_A`](crate::accel::RegisterAddress::OUT_Z_L_A).
+    Z,
+}
+
+impl AccelAxis {
+    /// Returns the register holding the low byte of the axis value.
+    pub const fn low_register(self) -> crate::accel::RegisterAddress {
+        use crate::accel::RegisterAddress;
+        match self {
+            AccelAxis::X => RegisterAddress::OUT_X_L_A,
+            AccelAxis::Y => RegisterAddress::OUT_Y_L_A,
+            AccelAxis::Z => RegisterAddress::OUT_Z_L_A,
+        }
+    }
+
+    /// Returns the register holding the high byte of the axis value.
+    pub const fn high_register(self) -> crate::accel::RegisterAddress {
+        use crate::accel::RegisterAddress;
+        match self {
+            AccelAxis::X => RegisterAddress::OUT_X_H_A,
+            AccelAxis::Y => RegisterAddress::OUT_Y_H_A,
+            AccelAxis::Z => RegisterAddress::OUT_Z_H_A,
+        }
+    }
+}
+
+/// A duration expressed in output data rate periods (`1/ODR`), as used by the
+/// interrupt duration and click timing registers.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+pub struct OdrTicks(pub u8);
+
+impl OdrTicks {
+    /// Converts the ticks into a duration at the specified data rate,
+    /// see [`AccelOdr::frequency_hz`].
+    ///
+    /// Returns [`Duration::ZERO`](core::time::Duration::ZERO) if the sensor is powered down.
+    pub fn to_duration(self, odr: AccelOdr) -> core::time::Duration {
+        let hz = odr.frequency_hz() as f64;
+        if hz <= 0.0 {
+            return core::time::Duration::ZERO;
+        }
+        let nanos = self.0 as f64 * 1e9 / hz;
+        core::time::Duration::from_nanos((nanos + 0.5) as u64)
+    }
+
+    /// Converts a duration into the nearest number of ticks at the specified data rate,
+    /// saturating at 255 ticks.
+    ///
+    /// Returns zero ticks if the sensor is powered down.
+    pub fn from_duration(duration: core::time::Duration, odr: AccelOdr) -> Self {
+        let ticks = duration.as_secs_f64() * odr.frequency_hz() as f64 + 0.5;
+        if ticks >= u8::MAX as f64 {
+            Self(u8::MAX)
+        } else {
+            Self(ticks as u8)
+        }
+    }
+
+    /// Returns the number of ticks, saturating at the 7-bit maximum of 127.
+    pub(crate) const fn saturating_7bit(self) -> u8 {
+        if self.0 > 0x7F {
+            0x7F
+        } else {
+            self.0
+        }
+    }
+}
+
+/// Per-axis status flags, e.g. as decoded from [`StatusRegisterA`](crate::accel::StatusRegisterA).
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+pub struct AxisFlags {
+    /// The flag for the X-axis.
+    pub x: bool,
+    /// The flag for the Y-axis.
+    pub y: bool,
+    /// The flag for the Z-axis.
+    pub z: bool,
+    /// The combined flag for the X-, Y- and Z-axis.
+    pub all: bool,
+}
+
+/// The high-pass filter configuration, as decoded from
+/// [`ControlRegister2A`](crate::accel::ControlRegister2A).
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+pub struct FilterConfig {
+    /// The high-pass filter mode.
+    pub mode: HighpassFilterMode,
+    /// The high-pass filter cutoff frequency.
+    pub cutoff: HighpassCutoff,
+    /// Whether filtered data is sent to the output registers and FIFO.
+    pub data_selected: bool,
+    /// Whether the high-pass filter is enabled for the click function.
+    pub click: bool,
+    /// Whether the high-pass filter is enabled for the AOI function on Interrupt 2.
+    pub int2: bool,
+    /// Whether the high-pass filter is enabled for the AOI function on Interrupt 1.
+    pub int1: bool,
+}
+
+/// A set of accelerometer axes, e.g. for enabling per-axis click detection.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+pub struct Axes(u8);
+
+impl Axes {
+    /// No axis.
+    pub const NONE: Self = Self(0b000);
+    /// The X-axis.
+    pub const X: Self = Self(0b001);
+    /// The Y-axis.
+    pub const Y: Self = Self(0b010);
+    /// The Z-axis.
+    pub const Z: Self = Self(0b100);
+    /// The X-, Y- and Z-axis.
+    pub const ALL: Self = Self(0b111);
+
+    /// Returns the axes as a bit mask with the X-axis in the least significant bit.
+    pub const fn bits(self) -> u8 {
+        self.0
+    }
+
+    /// Determines whether the X-axis is part of the set.
+    pub const fn x(self) -> bool {
+        self.0 & Self::X.0 != 0
+    }
+
+    /// Determines whether the Y-axis is part of the set.
+    pub const fn y(self) -> bool {
+        self.0 & Self::Y.0 != 0
+    }
+
+    /// Determines whether the Z-axis is part of the set.
+    pub const fn z(self) -> bool {
+        self.0 & Self::Z.0 != 0
+    }
+}
+
+flag_ops!(Axes);
+
+/// The interrupt sources routed to the INT1 pad, as configured in
+/// [`ControlRegister3A`](crate::accel::ControlRegister3A).
+///
+/// The flags use the bit positions of the register.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+pub struct Int1Routing(u8);
+
+impl Int1Routing {
+    /// No interrupt source.
+    pub const NONE: Self = Self(0);
+    /// The click interrupt (`I1_CLICK`).
+    pub const CLICK: Self = Self(0b1000_0000);
+    /// The AOI1 interrupt (`I1_AOI1`).
+    pub const AOI1: Self = Self(0b0100_0000);
+    /// The AOI2 interrupt (`I1_AOI2`).
+    pub const AOI2: Self = Self(0b0010_0000);
+    /// The data ready 1 interrupt (`I1_DRDY1`).
+    pub const DRDY1: Self = Self(0b0001_0000);
+    /// The data ready 2 interrupt (`I1_DRDY2`).
+    pub const DRDY2: Self = Self(0b0000_1000);
+    /// The FIFO watermark interrupt (`I1_WTM`).
+    pub const WATERMARK: Self = Self(0b0000_0100);
+    /// The FIFO overrun interrupt (`I1_OVERRUN`).
+    pub const OVERRUN: Self = Self(0b0000_0010);
+    /// All interrupt sources.
+    pub const ALL: Self = Self(0b1111_1110);
+
+    /// Returns the flags as laid out in the register.
+    pub const fn bits(self) -> u8 {
+        self.0
+    }
+
+    pub(crate) const fn from_bits(value: u8) -> Self {
+        Self(value & Self::ALL.0)
+    }
+}
+
+flag_ops!(Int1Routing);
+
+/// The interrupt sources routed to the INT2 pad, as configured in
+/// [`ControlRegister6A`](crate::accel::ControlRegister6A).
+///
+/// The flags use the bit positions of the register. The interrupt polarity is
+/// configured separately, see [`ControlRegister6A::active_low_interrupts`](crate::accel::ControlRegister6A::active_low_interrupts).
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+pub struct Int2Routing(u8);
+
+impl Int2Routing {
+    /// No interrupt source.
+    pub const NONE: Self = Self(0);
+    /// The click interrupt (`I2_CLICKen`).
+    pub const CLICK: Self = Self(0b1000_0000);
+    /// Interrupt 1 (`I2_INT1`).
+    pub const INT1: Self = Self(0b0100_0000);
+    /// Interrupt 2 (`I2_INT2`).
+    pub const INT2: Self = Self(0b0010_0000);
+    /// The memory reboot status (`BOOT_I1`).
+    pub const BOOT: Self = Self(0b0001_0000);
+    /// The active function status (`P2_ACT`).
+    pub const ACTIVE: Self = Self(0b0000_1000);
+    /// All interrupt sources.
+    pub const ALL: Self = Self(0b1111_1000);
+
+    /// Returns the flags as laid out in the register.
+    pub const fn bits(self) -> u8 {
+        self.0
+    }
+
+    pub(crate) const fn from_bits(value: u8) -> Self {
+        Self(value & Self::ALL.0)
+    }
+}
+
+flag_ops!(Int2Routing);
+
+/// The interrupt latch and 4D detection configuration, as decoded from
+/// [`ControlRegister5A`](crate::accel::ControlRegister5A).
+///
+/// 4D detection only takes effect if 6D detection is enabled in the corresponding
+/// [`Int1ConfigurationRegisterA`](crate::accel::Int1ConfigurationRegisterA) or
+/// [`Int2ConfigurationRegisterA`](crate::accel::Int2ConfigurationRegisterA).
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+pub struct LatchConfig {
+    /// Whether the interrupt request on INT1 is latched until `INT1_SRC_A` is read.
+    pub int1_latched: bool,
+    /// Whether the interrupt request on INT2 is latched until `INT2_SRC_A` is read.
+    pub int2_latched: bool,
+    /// Whether 4D detection is enabled on INT1.
+    pub int1_4d: bool,
+    /// Whether 4D detection is enabled on INT2.
+    pub int2_4d: bool,
+}
+
+enum_conversions!(AccelOdr {
+    Disabled,
+    Hz1,
+    Hz10,
+    Hz25,
+    Hz50,
+    Hz100,
+    Hz200,
+    Hz400,
+    LpHz1620,
+    LpHz1620NormalHz5376
+});
+
+enum_conversions!(Sensitivity { G1, G2, G4, G12 });
+
+enum_conversions!(SelfTest {
+    Disabled,
+    Mode0,
+    Mode1
+});
+
+enum_conversions!(FifoMode {
+    Bypass,
+    FIFO,
+    Stream,
+    Trigger
+});
+
+enum_conversions!(HighpassFilterMode {
+    NormalWithReset,
+    ReferenceSignal,
+    Normal,
+    AutoresetOnInterrupt
+});
+
+enum_conversions!(HighpassCutoff {
+    Highest,
+    High,
+    Low,
+    Lowest
+});
@@ -0,0 +1,99 @@
+//! Batching of register writes into a fixed-capacity buffer.
+
+use crate::{RegisterError, WritableRegister, AUTO_INCREMENT};
+
+/// Accumulates register writes into as few I²C write transactions as possible.
+///
+/// Registers of the same device at consecutive addresses are merged into a single
+/// segment consisting of the sub-address followed by all register values. For the
+/// accelerometer, the sub-address of a multi-byte segment has the auto-increment bit set.
+///
+/// `N` is the capacity in bytes, including the sub-address of every segment.
+///
+/// ```
+/// # use lsm303dlhc_registers::accel::{AccelOdr, ControlRegister1A, ControlRegister2A};
+/// # use lsm303dlhc_registers::{mag::ModeRegisterM, RegisterBatch};
+/// let mut batch = RegisterBatch::<8>::new();
+/// batch.push(&ControlRegister1A::new().with_output_data_rate(AccelOdr::Hz100))?;
+/// batch.push(&ControlRegister2A::new())?;
+/// assert_eq!(batch.as_bytes(), Some(&[0xA0, 0x57, 0x00][..]));
+///
+/// batch.push(&ModeRegisterM::new())?;
+/// assert_eq!(batch.as_bytes(), None);
+///
+/// let mut segments = batch.segments();
+/// assert_eq!(segments.next(), Some((0x19, &[0xA0, 0x57, 0x00][..])));
+/// assert_eq!(segments.next(), Some((0x1E, &[0x02, 0x03][..])));
+/// // for (device, bytes) in batch.segments() { i2c.write(device, bytes)?; }
+/// # Ok::<(), lsm303dlhc_registers::RegisterError>(())
+/// ```
+#[derive(Debug, Clone, Default)]
+pub struct RegisterBatch<const N: usize> {
+    bytes: heapless::Vec<u8, N>,
+    /// The device address and the start and end index of every segment in `bytes`.
+    segments: heapless::Vec<(u8, usize, usize), N>,
+}
+
+impl<const N: usize> RegisterBatch<N> {
+    /// Creates an empty batch.
+    pub fn new() -> Self {
+        Self {
+            bytes: heapless::Vec::new(),
+            segments: heapless::Vec::new(),
+        }
+    }
+
+    /// Appends a register write to the batch.
+    ///
+    /// Fails with [`RegisterError::CapacityExceeded`] if the batch cannot hold the write.
+    pub fn push(&mut self, register: &impl WritableRegister) -> Result<(), RegisterError> {
+        let (device, address, value) = register.write_tuple();
+
+        if let Some((last_device, start, end)) = self.segments.last_mut() {
+            let start_address = self.bytes[*start] & !AUTO_INCREMENT;
+            let next_address = start_address as usize + (*end - *start - 1);
+            if *last_device == device && next_address == address as usize {
+                self.bytes
+                    .push(value)
+                    .map_err(|_| RegisterError::CapacityExceeded)?;
+                if device == crate::accel::DEFAULT_DEVICE_ADDRESS {
+                    self.bytes[*start] |= AUTO_INCREMENT;
+                }
+                *end += 1;
+                return Ok(());
+            }
+        }
+
+        if N - self.bytes.len() < 2 {
+            return Err(RegisterError::CapacityExceeded);
+        }
+        let start = self.bytes.len();
+        self.segments
+            .push((device, start, start + 2))
+            .map_err(|_| RegisterError::CapacityExceeded)?;
+        self.bytes
+            .push(address)
+            .map_err(|_| RegisterError::CapacityExceeded)?;
+        self.bytes
+            .push(value)
+            .map_err(|_| RegisterError::CapacityExceeded)
+    }
+
+    /// Returns the 7-bit device address and the bytes to write of every segment.
+    pub fn segments(&self) -> impl Iterator<Item = (u8, &[u8])> + '_ {
+        self.segments
+            .iter()
+            .map(|&(device, start, end)| (device, &self.bytes[start..end]))
+    }
+
+    /// Returns the bytes of the only segment, i.e. a single I²C write.
+    ///
+    /// Returns `None` if the batch is empty or consists of more than one segment;
+    /// use [`segments`](Self::segments) in that case.
+    pub fn as_bytes(&self) -> Option<&[u8]> {
+        match *self.segments {
+            [(_, start, end)] => Some(&self.bytes[start..end]),
+            _ => None,
+        }
+    }
+}
@@ -0,0 +1,186 @@
+//! An aggregate of the configuration registers of both sensors.
+
+use crate::accel::*;
+use crate::mag::*;
+use crate::WritableRegister;
+
+/// The configuration registers of the accelerometer and magnetometer.
+///
+/// The [`Default`] value holds the power-on defaults of all registers.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+pub struct DeviceConfig {
+    /// See [`ControlRegister1A`].
+    pub ctrl1: ControlRegister1A,
+    /// See [`ControlRegister2A`].
+    pub ctrl2: ControlRegister2A,
+    /// See [`ControlRegister3A`].
+    pub ctrl3: ControlRegister3A,
+    /// See [`ControlRegister4A`].
+    pub ctrl4: ControlRegister4A,
+    /// See [`ControlRegister5A`].
+    pub ctrl5: ControlRegister5A,
+    /// See [`ControlRegister6A`].
+    pub ctrl6: ControlRegister6A,
+    /// See [`FifoControlRegisterA`].
+    pub fifo_ctrl: FifoControlRegisterA,
+    /// See [`ConfigurationARegisterM`].
+    pub cra: ConfigurationARegisterM,
+    /// See [`ConfigurationBRegisterM`].
+    pub crb: ConfigurationBRegisterM,
+    /// See [`ModeRegisterM`].
+    pub mr: ModeRegisterM,
+}
+
+impl DeviceConfig {
+    /// Returns the packed register values in field order, e.g. for persisting the configuration.
+    pub const fn to_bytes(&self) -> [u8; 10] {
+        [
+            self.ctrl1.into_bits(),
+            self.ctrl2.into_bits(),
+            self.ctrl3.into_bits(),
+            self.ctrl4.into_bits(),
+            self.ctrl5.into_bits(),
+            self.ctrl6.into_bits(),
+            self.fifo_ctrl.into_bits(),
+            self.cra.into_bits(),
+            self.crb.into_bits(),
+            self.mr.into_bits(),
+        ]
+    }
+
+    /// Computes a CRC-8 (polynomial `0x07`) over the [packed register values](Self::to_bytes).
+    pub const fn checksum(&self) -> u8 {
+        crc8(&self.to_bytes())
+    }
+
+    /// Determines whether the CRC-8 of `bytes` matches `checksum`; see [`checksum`](Self::checksum).
+    pub const fn verify(bytes: &[u8], checksum: u8) -> bool {
+        crc8(bytes) == checksum
+    }
+
+    /// Checks the configuration for mutually exclusive or ineffective settings.
+    pub const fn validate(&self) -> Result<(), ConfigError> {
+        if self.ctrl1.low_power_enable() && self.ctrl4.high_resolution() {
+            return Err(ConfigError::LowPowerWithHighResolution);
+        }
+
+        if !matches!(self.fifo_ctrl.fifo_mode(), FifoMode::Bypass) && !self.ctrl5.fifo_enable() {
+            return Err(ConfigError::FifoModeWithoutFifo);
+        }
+
+        let expects_data = self.ctrl5.fifo_enable()
+            || self.ctrl3.i1drdy1()
+            || self.ctrl3.i1drdy2()
+            || self.ctrl3.i1wtm()
+            || self.ctrl3.i1overrun();
+        if matches!(self.ctrl1.output_data_rate(), AccelOdr::Disabled) && expects_data {
+            return Err(ConfigError::DataRateDisabled);
+        }
+
+        Ok(())
+    }
+}
+
+/// Yields the `(device address, register address, value)` of every register in programming order.
+///
+/// The accelerometer is configured before [`ControlRegister1A`] sets its data rate, and the
+/// magnetometer is configured before [`ModeRegisterM`] takes it out of sleep mode.
+///
+/// The items carry the device address alongside the `(register, value)` pair because the
+/// accelerometer and magnetometer answer on different I²C addresses.
+///
+/// ```
+/// # use lsm303dlhc_registers::{accel, mag, DeviceConfig};
+/// # struct Bus;
+/// # impl Bus {
+/// #     fn write(&mut self, _: u8, _: &[u8]) -> Result<(), ()> { Ok(()) }
+/// # }
+/// # let mut i2c = Bus;
+/// let config = DeviceConfig::default();
+/// for (device, register, value) in &config {
+///     i2c.write(device, &[register, value])?;
+/// }
+///
+/// let (a, m) = (accel::DEFAULT_DEVICE_ADDRESS, mag::DEFAULT_DEVICE_ADDRESS);
+/// let order: heapless::Vec<(u8, u8), 10> =
+///     config.into_iter().map(|(device, register, _)| (device, register)).collect();
+/// assert_eq!(
+///     order[..],
+///     [
+///         (a, 0x21), (a, 0x22), (a, 0x23), (a, 0x24), (a, 0x25), (a, 0x2E), // CTRL_REG2_A..FIFO_CTRL_REG_A
+///         (a, 0x20), // CTRL_REG1_A, after the other accelerometer registers
+///         (m, 0x00), (m, 0x01), // CRA_REG_M, CRB_REG_M
+///         (m, 0x02), // MR_REG_M, last
+///     ]
+/// );
+/// # Ok::<(), ()>(())
+/// ```
+impl IntoIterator for &DeviceConfig {
+    type Item = (u8, u8, u8);
+    type IntoIter = core::array::IntoIter<(u8, u8, u8), 10>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        [
+            self.ctrl2.write_tuple(),
+            self.ctrl3.write_tuple(),
+            self.ctrl4.write_tuple(),
+            self.ctrl5.write_tuple(),
+            self.ctrl6.write_tuple(),
+            self.fifo_ctrl.write_tuple(),
+            self.ctrl1.write_tuple(),
+            self.cra.write_tuple(),
+            self.crb.write_tuple(),
+            self.mr.write_tuple(),
+        ]
+        .into_iter()
+    }
+}
+
+/// An invalid combination of settings in a [`DeviceConfig`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+pub enum ConfigError {
+    /// Low-power mode and high-resolution output mode are mutually exclusive.
+    LowPowerWithHighResolution,
+    /// A FIFO mode other than [`FifoMode::Bypass`] is selected, but the FIFO is not enabled.
+    FifoModeWithoutFifo,
+    /// The FIFO or a data interrupt is enabled, but the accelerometer is powered down.
+    DataRateDisabled,
+}
+
+impl core::fmt::Display for ConfigError {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        match self {
+            ConfigError::LowPowerWithHighResolution => {
+                f.write_str("low-power mode and high-resolution mode are mutually exclusive")
+            }
+            ConfigError::FifoModeWithoutFifo => f.write_str("FIFO mode selected but FIFO disabled"),
+            ConfigError::DataRateDisabled => {
+                f.write_str("data expected but accelerometer powered down")
+            }
+        }
+    }
+}
+
+impl core::error::Error for ConfigError {}
+
+/// Computes the CRC-8 with polynomial `0x07`, an initial value of zero and no reflection.
+const fn crc8(bytes: &[u8]) -> u8 {
+    let mut crc = 0u8;
+    let mut i = 0;
+    while i < bytes.len() {
+        crc ^= bytes[i];
+        let mut bit = 0;
+        while bit < 8 {
+            crc = if crc & 0x80 != 0 {
+                (crc << 1) ^ 0x07
+            } else {
+                crc << 1
+            };
+            bit += 1;
+        }
+        i += 1;
+    }
+    crc
+}
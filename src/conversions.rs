@@ -0,0 +1,32 @@
+//! Conversions shared between the accelerometer and magnetometer registers.
+
+/// Assembles a signed 16-bit value from its two's complement low and high bytes.
+///
+/// ```
+/// # use lsm303dlhc_registers::assemble_i16;
+/// assert_eq!(assemble_i16(0x00, 0x80), i16::MIN);
+/// ```
+pub const fn assemble_i16(low: u8, high: u8) -> i16 {
+    i16::from_le_bytes([low, high])
+}
+
+/// A data rate that formats as its frequency with a `Hz` suffix, e.g. `50 Hz`,
+/// including when logging via `defmt`.
+///
+/// See [`AccelOdr::display_hz`](crate::accel::AccelOdr::display_hz) and
+/// [`MagOdr::display_hz`](crate::mag::MagOdr::display_hz).
+#[derive(Debug, Default, Clone, Copy, PartialEq)]
+pub struct DisplayHz(pub f32);
+
+impl core::fmt::Display for DisplayHz {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        write!(f, "{} Hz", self.0)
+    }
+}
+
+#[cfg(feature = "defmt")]
+impl defmt::Format for DisplayHz {
+    fn format(&self, f: defmt::Formatter) {
+        defmt::write!(f, "{=f32} Hz", self.0);
+    }
+}
@@ -1,6 +1,16 @@
 //! # STMicroelectronics LSM303DLHC E-Compass Registers
 //!
 //! This crate provides a typed map of the LSM303DLHC's I²C registers.
+//!
+//! Registers are `Copy` values: the `with_*` builder methods return a modified copy
+//! rather than changing the register in place, and discarding their result is a warning.
+//!
+//! ```compile_fail
+//! #![deny(unused_must_use)]
+//! # use lsm303dlhc_registers::accel::ControlRegister1A;
+//! let reg = ControlRegister1A::new();
+//! reg.with_low_power_enable(true); // the result is discarded
+//! ```
 
 #![deny(missing_docs)]
 #![deny(warnings)]
@@ -9,8 +19,10 @@
 // Enables the `doc_cfg` feature when the `docsrs` configuration attribute is defined.
 #![cfg_attr(docsrs, feature(doc_cfg))]
 
-/// Exports commonly used traits.
+/// Exports commonly used traits and reading types.
 pub mod prelude {
+    pub use crate::accel::AccelReading;
+    pub use crate::mag::{MagReading, TemperatureReading};
     pub use crate::{Register, WritableRegister};
     pub use hardware_registers::i2c::*;
     pub use hardware_registers::sizes::R1;
@@ -19,9 +31,70 @@ pub mod prelude {
 
 macro_rules! readable_register {
     ($type:ident, $addr:expr) => {
-        impl $crate::Register for $type {}
+        impl $type {
+            /// The address of this register.
+            pub(crate) const ADDRESS: RegisterAddress = $addr;
+
+            /// Returns the raw register value, without requiring any trait to be in scope.
+            #[inline]
+            pub const fn as_byte(&self) -> u8 {
+                self.into_bits()
+            }
+
+            /// Returns the register with all bits cleared, unlike `new()` which applies
+            /// the field defaults.
+            #[inline]
+            pub const fn zeroed() -> Self {
+                Self::from_bits(0)
+            }
+
+            /// Compares the raw values of two registers, e.g. in `const` assertions.
+            #[inline]
+            pub const fn bits_eq(&self, other: &Self) -> bool {
+                self.into_bits() == other.into_bits()
+            }
+        }
+
+        impl $crate::Register for $type {
+            const ADDR: u8 = Self::ADDRESS.addr();
+
+            #[inline]
+            fn read_command(auto_increment: bool) -> [u8; 1] {
+                if auto_increment {
+                    [Self::ADDR | $crate::AUTO_INCREMENT]
+                } else {
+                    [Self::ADDR]
+                }
+            }
+        }
         impl $crate::prelude::HardwareRegister<$crate::prelude::R1> for $type {}
 
+        impl AsRef<u8> for $type {
+            #[inline]
+            fn as_ref(&self) -> &u8 {
+                &self.0
+            }
+        }
+
+        impl PartialEq<u8> for $type {
+            #[inline]
+            fn eq(&self, other: &u8) -> bool {
+                self.into_bits() == *other
+            }
+        }
+
+        impl core::fmt::LowerHex for $type {
+            fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+                core::fmt::LowerHex::fmt(&self.0, f)
+            }
+        }
+
+        impl core::fmt::UpperHex for $type {
+            fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+                core::fmt::UpperHex::fmt(&self.0, f)
+            }
+        }
+
         impl
             $crate::prelude::I2CRegister<
                 $crate::prelude::DeviceAddress7,
@@ -62,19 +135,955 @@ macro_rules! readable_register {
 macro_rules! writable_register {
     ($type:ident, $addr:expr) => {
         readable_register!($type, $addr);
+        impl $type {
+            /// Sets the specified raw bits in addition to the bits already set,
+            /// e.g. to preserve reserved bits read back from the sensor.
+            ///
+            /// This bypasses any field validation; see [`RESERVED_MASK`](Self::RESERVED_MASK).
+            #[inline]
+            pub const fn with_raw_bits(self, bits: u8) -> Self {
+                Self::from_bits(self.into_bits() | bits)
+            }
+
+            /// Applies `f` to the register, e.g. to override individual fields
+            /// of the [`Default`] value in a single expression.
+            #[inline]
+            pub fn map(self, f: impl FnOnce(Self) -> Self) -> Self {
+                f(self)
+            }
+        }
+
         impl $crate::prelude::WritableHardwareRegister<$crate::prelude::R1> for $type {}
-        impl $crate::WritableRegister for $type {}
+
+        impl $crate::WritableRegister for $type {
+            #[inline]
+            fn write_tuple(&self) -> (u8, u8, u8) {
+                (
+                    DEFAULT_DEVICE_ADDRESS,
+                    Self::ADDRESS.addr(),
+                    self.into_bits(),
+                )
+            }
+
+            #[inline]
+            fn write_command(&self) -> [u8; 2] {
+                [Self::ADDRESS.addr(), self.into_bits()]
+            }
+
+            #[inline]
+            fn describe(&self) -> $crate::WriteOp {
+                $crate::WriteOp {
+                    device: DEFAULT_DEVICE_ADDRESS,
+                    register: Self::ADDRESS.addr(),
+                    value: self.into_bits(),
+                    register_name: Self::ADDRESS.name(),
+                }
+            }
+        }
+    };
+}
+
+/// Asserts at compile time that every listed register type has the address given in the
+/// datasheet's register table and that no two types share an address.
+macro_rules! assert_register_addresses {
+    ($($type:ident => $address:literal),+ $(,)?) => {
+        const _: () = {
+            $(assert!(
+                $type::ADDRESS.addr() == $address,
+                concat!(stringify!($type), " is not mapped to address ", stringify!($address))
+            );)+
+            assert!(
+                $crate::addresses_unique(&[$($type::ADDRESS.addr()),+]),
+                "two register types share the same address"
+            );
+        };
+    };
+}
+
+/// Implements `From<$type> for u8` and a non-panicking `TryFrom<u8>` for a field enum.
+macro_rules! enum_conversions {
+    ($type:ident { $($variant:ident),+ $(,)? }) => {
+        impl From<$type> for u8 {
+            fn from(value: $type) -> Self {
+                value.into_bits()
+            }
+        }
+
+        impl TryFrom<u8> for $type {
+            type Error = $crate::RegisterError;
+
+            fn try_from(value: u8) -> Result<Self, Self::Error> {
+                $(
+                    if value == $type::$variant as u8 {
+                        return Ok($type::$variant);
+                    }
+                )+
+                Err($crate::RegisterError::ValueOutOfRange)
+            }
+        }
+    };
+}
+
+/// Defines the `WRITABLE_MASK` and `RESERVED_MASK` constants of the listed register types.
+macro_rules! register_masks {
+    ($($type:ident => ($writable:expr, $reserved:expr)),+ $(,)?) => {
+        $(
+            impl $type {
+                /// The bits of this register that can be written.
+                pub const WRITABLE_MASK: u8 = $writable;
+
+                /// The bits of this register that are reserved, i.e. not backed by a field.
+                pub const RESERVED_MASK: u8 = $reserved;
+            }
+
+            #[allow(clippy::bad_bit_mask)]
+            const _: () = assert!(
+                $type::WRITABLE_MASK & $type::RESERVED_MASK == 0,
+                concat!(stringify!($type), " has bits that are both writable and reserved")
+            );
+        )+
+    };
+}
+
+/// Defines the `POR_VALUE` constants of the listed register types,
+/// as per the reset column of the datasheet's register table.
+macro_rules! register_por_values {
+    ($($type:ident => $value:expr),+ $(,)?) => {
+        $(
+            impl $type {
+                /// The power-on reset value of this register, as per the datasheet.
+                ///
+                /// Output registers have no defined reset value and report zero.
+                pub const POR_VALUE: u8 = $value;
+            }
+        )+
     };
 }
 
 pub mod accel;
+#[cfg(feature = "heapless")]
+mod batch;
+mod config;
+mod conversions;
 pub mod mag;
 
+#[cfg(feature = "heapless")]
+#[cfg_attr(docsrs, doc(cfg(feature = "heapless")))]
+pub use batch::*;
+pub use config::*;
+pub use conversions::*;
+
+/// The sub-address bit enabling address auto-increment for multi-byte reads.
+///
+/// The accelerometer requires this bit to be set in order to read multiple bytes;
+/// the magnetometer increments its address pointer on its own.
+const AUTO_INCREMENT: u8 = 0b1000_0000;
+
 /// A sensor register.
-pub trait Register: prelude::I2CRegister8<prelude::DeviceAddress7> + From<u8> + Into<u8> {}
+pub trait Register: prelude::I2CRegister8<prelude::DeviceAddress7> + From<u8> + Into<u8> {
+    /// The 8-bit address of this register.
+    const ADDR: u8;
+
+    /// Returns the bytes to write in order to read this register, i.e. the register sub-address.
+    ///
+    /// If `auto_increment` is set, the sub-address has its most significant bit set,
+    /// allowing consecutive registers to be read in a single transaction.
+    ///
+    /// ```
+    /// # use lsm303dlhc_registers::accel::{OutXLowA, StatusRegisterA, DEFAULT_DEVICE_ADDRESS};
+    /// # use lsm303dlhc_registers::Register;
+    /// # struct Bus;
+    /// # impl Bus {
+    /// #     fn write_read(&mut self, _: u8, _: &[u8], _: &mut [u8]) -> Result<(), ()> { Ok(()) }
+    /// # }
+    /// # let mut i2c = Bus;
+    /// assert_eq!(StatusRegisterA::read_command(false), [0x27]);
+    /// assert_eq!(OutXLowA::read_command(true), [0xA8]);
+    ///
+    /// let mut value = [0];
+    /// i2c.write_read(DEFAULT_DEVICE_ADDRESS, &StatusRegisterA::read_command(false), &mut value)?;
+    /// let status = StatusRegisterA::from_bits(value[0]);
+    /// # Ok::<(), ()>(())
+    /// ```
+    fn read_command(auto_increment: bool) -> [u8; 1];
+
+    /// Returns a [`Debug`](core::fmt::Debug) representation of the register fields
+    /// that is followed by the raw register value, e.g. `ControlRegister1A { .. } (raw=0x07)`.
+    ///
+    /// This helps with cross-referencing register values against bus captures.
+    fn debug_with_raw(&self) -> DebugWithRaw<Self>
+    where
+        Self: Copy + core::fmt::Debug,
+    {
+        DebugWithRaw(*self)
+    }
+}
+
+/// Returns the 8-bit address of the register type `R`; see [`Register::ADDR`].
+///
+/// ```
+/// # use lsm303dlhc_registers::{accel::ControlRegister1A, reg_addr};
+/// assert_eq!(reg_addr::<ControlRegister1A>(), 0x20);
+/// ```
+pub const fn reg_addr<R: Register>() -> u8 {
+    R::ADDR
+}
+
+/// Describes a burst read of `N` consecutive registers.
+///
+/// ```
+/// # use lsm303dlhc_registers::{accel, mag, BurstRead};
+/// # struct Bus;
+/// # impl Bus {
+/// #     fn write_read(&mut self, _: u8, _: &[u8], _: &mut [u8]) -> Result<(), ()> { Ok(()) }
+/// # }
+/// # let mut i2c = Bus;
+/// let burst = BurstRead::accel_out();
+/// assert_eq!(burst.device(), accel::DEFAULT_DEVICE_ADDRESS);
+/// assert_eq!(burst.command(), [0xA8]);
+/// assert_eq!(BurstRead::<6>::LEN, 6);
+///
+/// let mut buf = [0; BurstRead::<6>::LEN];
+/// i2c.write_read(burst.device(), &burst.command(), &mut buf)?;
+///
+/// let burst = BurstRead::mag_out();
+/// assert_eq!(burst.device(), mag::DEFAULT_DEVICE_ADDRESS);
+/// assert_eq!(burst.command(), [0x03]);
+/// # Ok::<(), ()>(())
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+pub struct BurstRead<const N: usize> {
+    device: u8,
+    start: u8,
+}
+
+impl<const N: usize> BurstRead<N> {
+    /// The number of bytes to read.
+    pub const LEN: usize = N;
+
+    /// Returns the 7-bit device address.
+    pub const fn device(&self) -> u8 {
+        self.device
+    }
+
+    /// Returns the sub-address to write before reading, with the auto-increment bit set
+    /// where the device requires it.
+    pub const fn command(&self) -> [u8; 1] {
+        [self.start]
+    }
+}
+
+impl BurstRead<6> {
+    /// The accelerometer output registers, see [`accel::ACCEL_OUT_START`].
+    pub const fn accel_out() -> Self {
+        Self {
+            device: accel::DEFAULT_DEVICE_ADDRESS,
+            start: accel::ACCEL_OUT_START | AUTO_INCREMENT,
+        }
+    }
+
+    /// The magnetometer output registers, see [`mag::MAG_OUT_START`].
+    ///
+    /// The magnetometer increments the register address on its own.
+    pub const fn mag_out() -> Self {
+        Self {
+            device: mag::DEFAULT_DEVICE_ADDRESS,
+            start: mag::MAG_OUT_START,
+        }
+    }
+}
+
+/// Formats a register's fields followed by its raw value; see [`Register::debug_with_raw`].
+#[derive(Clone, Copy)]
+pub struct DebugWithRaw<R>(R);
+
+impl<R> core::fmt::Debug for DebugWithRaw<R>
+where
+    R: Register + Copy + core::fmt::Debug,
+{
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        let raw: u8 = self.0.into();
+        write!(f, "{:?} (raw=0x{:02X})", self.0, raw)
+    }
+}
 
 /// A writable sensor register.
 pub trait WritableRegister:
     prelude::WritableI2CRegister8<prelude::DeviceAddress7> + Register
 {
+    /// Returns the 7-bit device address, the register address and the register value,
+    /// i.e. everything required to write this register over I²C.
+    ///
+    /// ```
+    /// # use lsm303dlhc_registers::accel::ControlRegister1A;
+    /// # use lsm303dlhc_registers::WritableRegister;
+    /// # struct Bus;
+    /// # impl Bus {
+    /// #     fn write(&mut self, _: u8, _: &[u8]) -> Result<(), ()> { Ok(()) }
+    /// # }
+    /// # let mut i2c = Bus;
+    /// let (device, register, value) = ControlRegister1A::new().write_tuple();
+    /// assert_eq!((device, register, value), (0x19, 0x20, 0x07));
+    ///
+    /// i2c.write(device, &[register, value])?;
+    /// # Ok::<(), ()>(())
+    /// ```
+    fn write_tuple(&self) -> (u8, u8, u8);
+
+    /// Returns the bytes to write in order to update this register,
+    /// i.e. the register sub-address followed by the register value.
+    ///
+    /// ```
+    /// # use lsm303dlhc_registers::accel::{AccelOdr, ControlRegister1A, DEFAULT_DEVICE_ADDRESS};
+    /// # use lsm303dlhc_registers::WritableRegister;
+    /// # struct Bus;
+    /// # impl Bus {
+    /// #     fn write(&mut self, _: u8, _: &[u8]) -> Result<(), ()> { Ok(()) }
+    /// # }
+    /// # let mut i2c = Bus;
+    /// let reg = ControlRegister1A::new().with_output_data_rate(AccelOdr::Hz100);
+    /// assert_eq!(reg.write_command(), [0x20, 0x57]);
+    ///
+    /// i2c.write(DEFAULT_DEVICE_ADDRESS, &reg.write_command())?;
+    /// # Ok::<(), ()>(())
+    /// ```
+    fn write_command(&self) -> [u8; 2];
+
+    /// Describes the write of this register, e.g. for logging it before it is performed.
+    ///
+    /// ```
+    /// # use lsm303dlhc_registers::accel::ControlRegister1A;
+    /// # use lsm303dlhc_registers::WritableRegister;
+    /// let op = ControlRegister1A::new().describe();
+    /// assert_eq!(op.register_name, "CTRL_REG1_A");
+    /// assert_eq!(op.register, 0x20);
+    /// ```
+    fn describe(&self) -> WriteOp;
+}
+
+/// A single register write over I²C; see [`WritableRegister::describe`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+pub struct WriteOp {
+    /// The 7-bit device address.
+    pub device: u8,
+    /// The register sub-address.
+    pub register: u8,
+    /// The register value.
+    pub value: u8,
+    /// The datasheet name of the register.
+    pub register_name: &'static str,
+}
+
+/// An error raised by fallible register conversions.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+pub enum RegisterError {
+    /// A value does not fit into its register field.
+    ValueOutOfRange,
+    /// The byte does not correspond to a known register address.
+    UnknownRegisterAddress(u8),
+    /// The number of bytes did not match the expected length.
+    InvalidLength {
+        /// The expected number of bytes.
+        expected: usize,
+        /// The actual number of bytes.
+        actual: usize,
+    },
+    /// Bits marked as reserved in the datasheet were set; contains the offending bits.
+    ReservedBitsSet(u8),
+    /// A fixed-capacity buffer cannot hold any more data.
+    CapacityExceeded,
+}
+
+impl core::fmt::Display for RegisterError {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        match self {
+            RegisterError::ValueOutOfRange => f.write_str("value out of range"),
+            RegisterError::UnknownRegisterAddress(address) => {
+                write!(f, "unknown register address 0x{address:02X}")
+            }
+            RegisterError::InvalidLength { expected, actual } => {
+                write!(f, "expected {expected} bytes, got {actual}")
+            }
+            RegisterError::ReservedBitsSet(bits) => write!(f, "reserved bits set: 0b{bits:08b}"),
+            RegisterError::CapacityExceeded => f.write_str("capacity exceeded"),
+        }
+    }
+}
+
+impl core::error::Error for RegisterError {}
+
+/// Returns the 8-bit I²C address for reading from the device with the 7-bit address `dev7`,
+/// i.e. the address followed by a read/write bit of `1`.
+pub const fn read_address(dev7: u8) -> u8 {
+    (dev7 << 1) | 1
+}
+
+/// Returns the 8-bit I²C address for writing to the device with the 7-bit address `dev7`,
+/// i.e. the address followed by a read/write bit of `0`.
+pub const fn write_address(dev7: u8) -> u8 {
+    dev7 << 1
+}
+
+/// The 8-bit I²C address for reading from the accelerometer.
+pub const ACCEL_READ_ADDR: u8 = read_address(accel::DEFAULT_DEVICE_ADDRESS);
+
+/// The 8-bit I²C address for writing to the accelerometer.
+pub const ACCEL_WRITE_ADDR: u8 = write_address(accel::DEFAULT_DEVICE_ADDRESS);
+
+/// The 8-bit I²C address for reading from the magnetometer.
+pub const MAG_READ_ADDR: u8 = read_address(mag::DEFAULT_DEVICE_ADDRESS);
+
+/// The 8-bit I²C address for writing to the magnetometer.
+pub const MAG_WRITE_ADDR: u8 = write_address(mag::DEFAULT_DEVICE_ADDRESS);
+
+/// Determines whether all addresses in the slice are distinct.
+///
+/// This is an implementation detail of the compile-time register address checks
+/// and not part of the public API. It verifies that no two register types share an address:
+///
+/// ```
+/// # use lsm303dlhc_registers::addresses_unique;
+/// const _: () = assert!(addresses_unique(&[0x2E, 0x2F, 0x30]));
+/// ```
+///
+/// A duplicate address fails the build:
+///
+/// ```compile_fail
+/// # use lsm303dlhc_registers::addresses_unique;
+/// const _: () = assert!(addresses_unique(&[0x2E, 0x2F, 0x2F]));
+/// ```
+#[doc(hidden)]
+pub const fn addresses_unique(addresses: &[u8]) -> bool {
+    let mut i = 0;
+    while i < addresses.len() {
+        let mut j = i + 1;
+        while j < addresses.len() {
+            if addresses[i] == addresses[j] {
+                return false;
+            }
+            j += 1;
+        }
+        i += 1;
+    }
+    true
+}
+
+/// Expands to `(register address, value)` pairs of the given registers in their default state.
+macro_rules! register_defaults {
+    ($($type:ident),+ $(,)?) => {
+        [$(($type::ADDRESS.addr(), $type::POR_VALUE)),+]
+    };
+}
+
+/// Returns the power-on defaults of all writable accelerometer registers as
+/// `(register address, value)` pairs in ascending address order.
+///
+/// Writing these values resets the accelerometer configuration.
+pub const fn default_accel_config() -> [(u8, u8); 19] {
+    use accel::*;
+    register_defaults![
+        ControlRegister1A,
+        ControlRegister2A,
+        ControlRegister3A,
+        ControlRegister4A,
+        ControlRegister5A,
+        ControlRegister6A,
+        ReferenceRegisterA,
+        FifoControlRegisterA,
+        Int1ConfigurationRegisterA,
+        Int1ThresholdRegisterA,
+        Int1DurationRegisterA,
+        Int2ConfigurationRegisterA,
+        Int2ThresholdRegisterA,
+        Int2DurationRegisterA,
+        ClickConfigurationRegisterA,
+        ClickThresholdRegisterA,
+        ClickTimeLimitRegisterA,
+        ClickTimeLatencyRegisterA,
+        ClickTimeWindowRegisterA,
+    ]
+}
+
+/// Returns the power-on defaults of all writable magnetometer registers as
+/// `(register address, value)` pairs in ascending address order.
+///
+/// Writing these values resets the magnetometer configuration.
+pub const fn default_mag_config() -> [(u8, u8); 3] {
+    use mag::*;
+    register_defaults![
+        ConfigurationARegisterM,
+        ConfigurationBRegisterM,
+        ModeRegisterM
+    ]
+}
+
+/// Returns the register addresses of `(register address, value)` pairs.
+const fn addresses_of<const N: usize>(pairs: [(u8, u8); N]) -> [u8; N] {
+    let mut addresses = [0; N];
+    let mut i = 0;
+    while i < N {
+        addresses[i] = pairs[i].0;
+        i += 1;
+    }
+    addresses
+}
+
+/// The set of registers forming the configuration of a sensor, e.g. for dumping
+/// and restoring it.
+pub trait RegisterSet {
+    /// Returns the addresses of all writable registers of the sensor in ascending order.
+    fn control_registers() -> &'static [u8];
+}
+
+/// Marker type for the accelerometer; see [`RegisterSet`].
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+pub struct Accel;
+
+/// Marker type for the magnetometer; see [`RegisterSet`].
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+pub struct Mag;
+
+impl RegisterSet for Accel {
+    fn control_registers() -> &'static [u8] {
+        const ADDRESSES: [u8; 19] = addresses_of(default_accel_config());
+        &ADDRESSES
+    }
+}
+
+impl RegisterSet for Mag {
+    fn control_registers() -> &'static [u8] {
+        const ADDRESSES: [u8; 3] = addresses_of(default_mag_config());
+        &ADDRESSES
+    }
+}
+
+/// Defines [`DecodedRegister`] and [`decode`] for the listed register types of each sensor module.
+macro_rules! decoded_registers {
+    ($($device:ident => [$($type:ident),+ $(,)?]),+ $(,)?) => {
+        /// A typed register, as decoded from a device address, register address and value;
+        /// see [`decode`].
+        #[derive(Debug, Clone, Copy, PartialEq, Eq)]
+        #[cfg_attr(feature = "defmt", derive(defmt::Format))]
+        pub enum DecodedRegister {
+            $($(
+                #[doc = concat!("See [`", stringify!($type), "`](", stringify!($device), "::", stringify!($type), ").")]
+                $type($device::$type),
+            )+)+
+        }
+
+        /// Decodes a register value captured from the bus, e.g. to pretty-print a register dump.
+        ///
+        /// The device address is required because the accelerometer and magnetometer
+        /// share some register addresses. Returns `None` for unknown addresses.
+        ///
+        /// ```
+        /// # use lsm303dlhc_registers::{accel, decode, DecodedRegister};
+        /// let decoded = decode(accel::DEFAULT_DEVICE_ADDRESS, 0x20, 0x57);
+        /// assert!(matches!(decoded, Some(DecodedRegister::ControlRegister1A(_))));
+        /// ```
+        pub fn decode(device: u8, address: u8, value: u8) -> Option<DecodedRegister> {
+            $(
+                if device == $device::DEFAULT_DEVICE_ADDRESS {
+                    $(
+                        if address == <$device::$type as Register>::ADDR {
+                            return Some(DecodedRegister::$type($device::$type::from_bits(value)));
+                        }
+                    )+
+                }
+            )+
+            None
+        }
+    };
+}
+
+decoded_registers! {
+    accel => [
+        ControlRegister1A,
+        ControlRegister2A,
+        ControlRegister3A,
+        ControlRegister4A,
+        ControlRegister5A,
+        ControlRegister6A,
+        ReferenceRegisterA,
+        StatusRegisterA,
+        OutXLowA,
+        OutXHighA,
+        OutYLowA,
+        OutYHighA,
+        OutZLowA,
+        OutZHighA,
+        FifoControlRegisterA,
+        FifoSourceRegisterA,
+        Int1ConfigurationRegisterA,
+        Int1SourceRegisterA,
+        Int1ThresholdRegisterA,
+        Int1DurationRegisterA,
+        Int2ConfigurationRegisterA,
+        Int2SourceRegisterA,
+        Int2ThresholdRegisterA,
+        Int2DurationRegisterA,
+        ClickConfigurationRegisterA,
+        ClickSourceRegisterA,
+        ClickThresholdRegisterA,
+        ClickTimeLimitRegisterA,
+        ClickTimeLatencyRegisterA,
+        ClickTimeWindowRegisterA,
+    ],
+    mag => [
+        ConfigurationARegisterM,
+        ConfigurationBRegisterM,
+        ModeRegisterM,
+        OutXHighM,
+        OutXLowM,
+        OutZHighM,
+        OutZLowM,
+        OutYHighM,
+        OutYLowM,
+        StatusRegisterM,
+        IdentificationARegisterM,
+        IdentificationBRegisterM,
+        IdentificationCRegisterM,
+        TemperatureOutHighM,
+        TemperatureOutLowM,
+    ],
+}
+
+/// Returns a ready-to-write configuration for a typical e-compass application.
+///
+/// * [`ControlRegister1A`](accel::ControlRegister1A): 50 Hz accelerometer data rate
+///   in normal mode, with all axes enabled.
+/// * [`ControlRegister4A`](accel::ControlRegister4A): ±2g full scale in high-resolution mode,
+///   with block data update enabled so that readings never mix bytes of different samples.
+/// * [`ConfigurationARegisterM`](mag::ConfigurationARegisterM): 15 Hz magnetometer data rate.
+/// * [`ConfigurationBRegisterM`](mag::ConfigurationBRegisterM): ±1.3 Gauss field range.
+/// * [`ModeRegisterM`](mag::ModeRegisterM): continuous conversion, which takes the
+///   magnetometer out of its default sleep mode.
+pub const fn ecompass_defaults() -> (
+    accel::ControlRegister1A,
+    accel::ControlRegister4A,
+    mag::ConfigurationARegisterM,
+    mag::ConfigurationBRegisterM,
+    mag::ModeRegisterM,
+) {
+    use accel::*;
+    use mag::*;
+    (
+        ControlRegister1A::new().with_output_data_rate(AccelOdr::Hz50),
+        ControlRegister4A::new()
+            .with_block_data_update(true)
+            .with_full_scale(Sensitivity::G1)
+            .with_high_resolution(true),
+        ConfigurationARegisterM::new().with_data_output_rate(MagOdr::Hz15),
+        ConfigurationBRegisterM::new().with_gain(MagGain::Gauss1_3),
+        ModeRegisterM::new()
+            .with_sleep_mode(false)
+            .with_single_conversion(false),
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn register_error_display() {
+        extern crate std;
+        use std::string::ToString;
+
+        assert_eq!(
+            RegisterError::ValueOutOfRange.to_string(),
+            "value out of range"
+        );
+        assert_eq!(
+            RegisterError::UnknownRegisterAddress(0x0A).to_string(),
+            "unknown register address 0x0A"
+        );
+        assert_eq!(
+            RegisterError::InvalidLength {
+                expected: 6,
+                actual: 5
+            }
+            .to_string(),
+            "expected 6 bytes, got 5"
+        );
+        assert_eq!(
+            RegisterError::ReservedBitsSet(0x81).to_string(),
+            "reserved bits set: 0b10000001"
+        );
+        assert_eq!(
+            RegisterError::CapacityExceeded.to_string(),
+            "capacity exceeded"
+        );
+    }
+
+    #[test]
+    fn assemble_extremes() {
+        assert_eq!(assemble_i16(0xFF, 0x7F), i16::MAX);
+        assert_eq!(assemble_i16(0x00, 0x80), i16::MIN);
+        assert_eq!(assemble_i16(0xFF, 0xFF), -1);
+    }
+
+    #[test]
+    fn validate_config() {
+        use crate::accel::*;
+
+        let mut config = DeviceConfig::default();
+        config.ctrl1 = config.ctrl1.with_output_data_rate(AccelOdr::Hz100);
+        config.ctrl5 = config.ctrl5.with_fifo_enable(true);
+        config.fifo_ctrl = config.fifo_ctrl.with_fifo_mode(FifoMode::Stream);
+        assert_eq!(config.validate(), Ok(()));
+
+        let mut invalid = config;
+        invalid.ctrl1 = invalid.ctrl1.with_low_power_enable(true);
+        invalid.ctrl4 = invalid.ctrl4.with_high_resolution(true);
+        assert_eq!(
+            invalid.validate(),
+            Err(ConfigError::LowPowerWithHighResolution)
+        );
+
+        let mut invalid = config;
+        invalid.ctrl5 = invalid.ctrl5.with_fifo_enable(false);
+        assert_eq!(invalid.validate(), Err(ConfigError::FifoModeWithoutFifo));
+    }
+
+    #[test]
+    #[cfg(feature = "heapless")]
+    fn register_batch() {
+        use crate::accel::*;
+        use crate::mag::ModeRegisterM;
+
+        let mut batch = RegisterBatch::<6>::new();
+        batch
+            .push(&ControlRegister1A::new().with_output_data_rate(AccelOdr::Hz100))
+            .unwrap();
+        batch.push(&ControlRegister2A::new()).unwrap();
+        batch
+            .push(&ControlRegister3A::new().with_i1drdy1(true))
+            .unwrap();
+        batch.push(&ModeRegisterM::new()).unwrap();
+
+        assert_eq!(
+            batch.push(&ControlRegister4A::new()),
+            Err(RegisterError::CapacityExceeded)
+        );
+
+        let mut segments = batch.segments();
+        assert_eq!(
+            segments.next(),
+            Some((0b0011001, &[0xA0, 0x57, 0x00, 0x10][..]))
+        );
+        assert_eq!(segments.next(), Some((0b0011110, &[0x02, 0x03][..])));
+        assert_eq!(segments.next(), None);
+        assert_eq!(batch.as_bytes(), None);
+
+        let mut single = RegisterBatch::<4>::new();
+        assert_eq!(single.as_bytes(), None);
+        single.push(&ModeRegisterM::new()).unwrap();
+        assert_eq!(single.as_bytes(), Some(&[0x02, 0x03][..]));
+    }
+
+    #[test]
+    fn config_checksum() {
+        let config = DeviceConfig::default();
+        let bytes = config.to_bytes();
+        let checksum = config.checksum();
+        assert!(DeviceConfig::verify(b"123456789", 0xF4));
+        assert!(DeviceConfig::verify(&bytes, checksum));
+
+        let mut flipped = bytes;
+        flipped[3] ^= 0b0000_1000;
+        assert!(!DeviceConfig::verify(&flipped, checksum));
+
+        let mut changed = config;
+        changed.ctrl4 = changed.ctrl4.with_high_resolution(true);
+        assert_ne!(changed.checksum(), checksum);
+    }
+
+    #[test]
+    fn generic_register_address() {
+        assert_eq!(reg_addr::<accel::StatusRegisterA>(), 0x27);
+        assert_eq!(reg_addr::<mag::ModeRegisterM>(), 0x02);
+    }
+
+    #[test]
+    fn config_write_order() {
+        let config = DeviceConfig::default();
+        let writes: heapless::Vec<(u8, u8, u8), 10> = config.into_iter().collect();
+        let registers: heapless::Vec<u8, 10> = writes.iter().map(|w| w.1).collect();
+        assert_eq!(
+            registers[..],
+            [0x21, 0x22, 0x23, 0x24, 0x25, 0x2E, 0x20, 0x00, 0x01, 0x02]
+        );
+        assert_eq!(writes[6], (0b0011001, 0x20, 0x07));
+        assert_eq!(writes[9], (0b0011110, 0x02, 0x03));
+    }
+
+    #[test]
+    fn eight_bit_addresses() {
+        assert_eq!(ACCEL_WRITE_ADDR, 0x32);
+        assert_eq!(ACCEL_READ_ADDR, 0x33);
+        assert_eq!(MAG_WRITE_ADDR, 0x3C);
+        assert_eq!(MAG_READ_ADDR, 0x3D);
+    }
+
+    #[test]
+    fn describe_write() {
+        let op = accel::ControlRegister1A::new().describe();
+        assert_eq!(op.register_name, "CTRL_REG1_A");
+        assert_eq!(op.device, accel::DEFAULT_DEVICE_ADDRESS);
+        assert_eq!(op.value, 0x07);
+
+        let op = mag::ModeRegisterM::single_shot().describe();
+        assert_eq!(op.register_name, "MR_REG_M");
+        assert_eq!((op.register, op.value), (0x02, 0x01));
+    }
+
+    #[test]
+    fn por_values() {
+        assert_eq!(accel::ControlRegister1A::POR_VALUE, 0x07);
+        assert_eq!(accel::ControlRegister4A::POR_VALUE, 0x00);
+        assert_eq!(mag::ConfigurationARegisterM::POR_VALUE, 0x10);
+        assert_eq!(mag::ConfigurationBRegisterM::POR_VALUE, 0x20);
+        assert_eq!(mag::ModeRegisterM::POR_VALUE, 0x03);
+        assert_eq!(mag::IdentificationARegisterM::POR_VALUE, 0x48);
+        assert_eq!(mag::IdentificationBRegisterM::POR_VALUE, 0x34);
+        assert_eq!(mag::IdentificationCRegisterM::POR_VALUE, 0x33);
+    }
+
+    #[test]
+    fn register_sets() {
+        use accel::RegisterAddress::*;
+        let registers = Accel::control_registers();
+        for address in [
+            CTRL_REG1_A,
+            CTRL_REG2_A,
+            CTRL_REG3_A,
+            CTRL_REG4_A,
+            CTRL_REG5_A,
+            CTRL_REG6_A,
+        ] {
+            assert!(registers.contains(&address.addr()));
+        }
+        assert!(!registers.contains(&STATUS_REG_A.addr()));
+        assert_eq!(Mag::control_registers(), [0x00, 0x01, 0x02]);
+    }
+
+    #[test]
+    fn zeroed_registers() {
+        assert_eq!(accel::ControlRegister1A::zeroed().into_bits(), 0x00);
+        assert_eq!(accel::ControlRegister1A::new().into_bits(), 0x07);
+        assert_eq!(mag::ModeRegisterM::zeroed().into_bits(), 0x00);
+        assert_eq!(mag::ModeRegisterM::new().into_bits(), 0x03);
+    }
+
+    #[test]
+    fn const_equality() {
+        const A: accel::ControlRegister1A = accel::ControlRegister1A::new();
+        const B: accel::ControlRegister1A = accel::ControlRegister1A::from_bits(0x07);
+        const _: () = assert!(A.bits_eq(&B));
+        const _: () = assert!(!A.bits_eq(&accel::ControlRegister1A::zeroed()));
+    }
+
+    #[test]
+    fn display_hz() {
+        extern crate std;
+        use std::string::ToString;
+
+        assert_eq!(accel::AccelOdr::Hz400.display_hz().to_string(), "400 Hz");
+        assert_eq!(mag::MagOdr::Hz0_75.display_hz().to_string(), "0.75 Hz");
+    }
+
+    #[cfg(feature = "defmt")]
+    #[test]
+    fn display_hz_is_defmt_format() {
+        fn assert_format<T: defmt::Format>(_: T) {}
+        assert_format(accel::AccelOdr::Hz50.display_hz());
+        assert_format(mag::MagOdr::Hz15.display_hz());
+    }
+
+    #[test]
+    fn burst_reads() {
+        let accel = BurstRead::accel_out();
+        assert_eq!(accel.device(), accel::DEFAULT_DEVICE_ADDRESS);
+        assert_eq!(accel.command(), [0xA8]);
+
+        let mag = BurstRead::mag_out();
+        assert_eq!(mag.device(), mag::DEFAULT_DEVICE_ADDRESS);
+        assert_eq!(mag.command(), [0x03]);
+
+        assert_eq!(BurstRead::<6>::LEN, accel::ACCEL_OUT_LEN);
+        assert_eq!(BurstRead::<6>::LEN, mag::MAG_OUT_LEN);
+    }
+
+    #[test]
+    fn decode_captured_bytes() {
+        let decoded = decode(accel::DEFAULT_DEVICE_ADDRESS, 0x20, 0x57);
+        let Some(DecodedRegister::ControlRegister1A(reg)) = decoded else {
+            panic!("unexpected register {decoded:?}");
+        };
+        assert_eq!(reg.output_data_rate(), accel::AccelOdr::Hz100);
+
+        assert!(matches!(
+            decode(mag::DEFAULT_DEVICE_ADDRESS, 0x31, 0x01),
+            Some(DecodedRegister::TemperatureOutHighM(_))
+        ));
+        assert!(matches!(
+            decode(accel::DEFAULT_DEVICE_ADDRESS, 0x31, 0x01),
+            Some(DecodedRegister::Int1SourceRegisterA(_))
+        ));
+        assert_eq!(decode(accel::DEFAULT_DEVICE_ADDRESS, 0x00, 0x00), None);
+        assert_eq!(decode(0x00, 0x20, 0x00), None);
+    }
+
+    #[test]
+    fn hex_formatting() {
+        extern crate std;
+        use std::format;
+
+        let reg = accel::ControlRegister1A::new();
+        assert_eq!(format!("{:#04x}", reg), "0x07");
+        assert_eq!(
+            format!("{:02X}", mag::IdentificationARegisterM::new()),
+            "48"
+        );
+        assert_eq!(
+            format!("{:x}", accel::ControlRegister1A::from_bits(0xAB)),
+            "ab"
+        );
+    }
+
+    #[test]
+    fn ecompass() {
+        let (ctrl1, ctrl4, cra, crb, mr) = ecompass_defaults();
+        assert_eq!(ctrl1.into_bits(), 0x47);
+        assert_eq!(ctrl4.into_bits(), 0x88);
+        assert_eq!((cra.into_bits(), crb.into_bits()), (0x10, 0x20));
+        assert!(!mr.sleep_mode());
+        assert!(!mr.single_conversion());
+    }
+
+    #[test]
+    fn prelude_exports_readings() {
+        use crate::prelude::*;
+
+        let accel = AccelReading::new(1, 2, 3);
+        let mag = MagReading::new(4, 5, 6);
+        let temp = TemperatureReading { raw: 7 };
+        assert_eq!((accel.z, mag.z, temp.raw), (3, 6, 7));
+    }
+
+    #[test]
+    fn default_configs() {
+        let accel = default_accel_config();
+        assert_eq!(accel[0], (0x20, 0x07));
+        assert!(accel.iter().skip(1).all(|&(_, value)| value == 0));
+
+        let mag = default_mag_config();
+        assert_eq!(mag, [(0x00, 0x10), (0x01, 0x20), (0x02, 0x03)]);
+    }
 }
@@ -7,6 +7,7 @@ mod conversions;
 mod types;
 
 use bitfield_struct::bitfield;
+pub use conversions::*;
 pub use types::*;
 
 /// The I2C bus address.
@@ -24,7 +25,7 @@ pub const DEFAULT_DEVICE_ADDRESS: u8 = 0b0011110;
 #[allow(dead_code)]
 #[allow(non_camel_case_types)]
 #[allow(missing_docs)]
-#[derive(Copy, Clone, PartialEq, Eq)]
+#[derive(Copy, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
 #[cfg_attr(feature = "defmt", derive(defmt::Format))]
 pub enum RegisterAddress {
     /// See [`ConfigurationARegisterM`].
@@ -64,6 +65,40 @@ impl RegisterAddress {
     pub const fn addr(&self) -> u8 {
         *self as u8
     }
+
+    /// Returns the datasheet name of the register, e.g. `"CRA_REG_M"`.
+    pub const fn name(self) -> &'static str {
+        match self {
+            Self::CRA_REG_M => "CRA_REG_M",
+            Self::CRB_REG_M => "CRB_REG_M",
+            Self::MR_REG_M => "MR_REG_M",
+            Self::OUT_X_H_M => "OUT_X_H_M",
+            Self::OUT_X_L_M => "OUT_X_L_M",
+            Self::OUT_Z_H_M => "OUT_Z_H_M",
+            Self::OUT_Z_L_M => "OUT_Z_L_M",
+            Self::OUT_Y_H_M => "OUT_Y_H_M",
+            Self::OUT_Y_L_M => "OUT_Y_L_M",
+            Self::SR_REG_M => "SR_REG_M",
+            Self::IRA_REG_M => "IRA_REG_M",
+            Self::IRB_REG_M => "IRB_REG_M",
+            Self::IRC_REG_M => "IRC_REG_M",
+            Self::TEMP_OUT_H_M => "TEMP_OUT_H_M",
+            Self::TEMP_OUT_L_M => "TEMP_OUT_L_M",
+        }
+    }
+
+    /// Determines whether the register can be read. All magnetometer registers are readable.
+    pub const fn is_readable(self) -> bool {
+        true
+    }
+
+    /// Determines whether the register can be written, as per the datasheet access column.
+    ///
+    /// Only [`CRA_REG_M`](Self::CRA_REG_M), [`CRB_REG_M`](Self::CRB_REG_M) and
+    /// [`MR_REG_M`](Self::MR_REG_M) are writable.
+    pub const fn is_writable(self) -> bool {
+        matches!(self, Self::CRA_REG_M | Self::CRB_REG_M | Self::MR_REG_M)
+    }
 }
 
 impl From<RegisterAddress> for u8 {
@@ -72,9 +107,34 @@ impl From<RegisterAddress> for u8 {
     }
 }
 
+/// The magnetometer output registers in the order in which they are physically read.
+///
+/// Note that unlike the accelerometer, the magnetometer orders its axes X-Z-Y
+/// and stores the high byte first. See [`MagReading::from_register_bytes`].
+pub const MAG_OUT_ADDRESSES: [RegisterAddress; 6] = [
+    RegisterAddress::OUT_X_H_M,
+    RegisterAddress::OUT_X_L_M,
+    RegisterAddress::OUT_Z_H_M,
+    RegisterAddress::OUT_Z_L_M,
+    RegisterAddress::OUT_Y_H_M,
+    RegisterAddress::OUT_Y_L_M,
+];
+
+/// The first output register, [`OUT_X_H_M`](RegisterAddress::OUT_X_H_M), for burst reads
+/// of [`MAG_OUT_LEN`] bytes.
+///
+/// The magnetometer increments the register address automatically, so no
+/// auto-increment bit needs to be set. The bytes arrive in the order of [`MAG_OUT_ADDRESSES`].
+pub const MAG_OUT_START: u8 = RegisterAddress::OUT_X_H_M.addr();
+
+/// The number of output register bytes, from [`OUT_X_H_M`](RegisterAddress::OUT_X_H_M)
+/// through [`OUT_Y_L_M`](RegisterAddress::OUT_Y_L_M).
+pub const MAG_OUT_LEN: usize = 6;
+
 /// [`CRA_REG_M`](RegisterAddress::CRA_REG_M) (00h)
 #[bitfield(u8, order = Msb)]
-#[derive(PartialEq, Eq)]
+#[derive(PartialEq, Eq, Hash)]
+#[must_use]
 #[cfg_attr(feature = "defmt", derive(defmt::Format))]
 pub struct ConfigurationARegisterM {
     /// Temperature sensor enabled.
@@ -90,7 +150,7 @@ pub struct ConfigurationARegisterM {
     ///
     /// Note that the datasheet has inconsistent information about this field.
     /// While section 7.2.1 reports default of `100`, i.e. 15 Hz, the register mapping
-    /// table 17 appears to be missing a zero.
+    /// table 17 appears to be missing a zero. See [`MagOdr::from_bits_lenient`].
     #[bits(3, access = RW, default = MagOdr::Hz15)]
     pub data_output_rate: MagOdr,
 
@@ -101,11 +161,22 @@ pub struct ConfigurationARegisterM {
 
 writable_register!(ConfigurationARegisterM, RegisterAddress::CRA_REG_M);
 
+impl ConfigurationARegisterM {
+    /// Enables or disables the temperature sensor.
+    ///
+    /// The [`TemperatureOutHighM`] and [`TemperatureOutLowM`] registers only provide
+    /// valid data while the temperature sensor is enabled.
+    pub const fn with_temperature(self, enable: bool) -> Self {
+        self.with_temp_en(enable)
+    }
+}
+
 /// Magnetometer gain configuration.
 ///
 /// [`CRB_REG_M`](RegisterAddress::CRB_REG_M) (01h)
 #[bitfield(u8, order = Msb)]
-#[derive(PartialEq, Eq)]
+#[derive(PartialEq, Eq, Hash)]
+#[must_use]
 #[cfg_attr(feature = "defmt", derive(defmt::Format))]
 pub struct ConfigurationBRegisterM {
     /// Gain configuration.
@@ -123,7 +194,8 @@ writable_register!(ConfigurationBRegisterM, RegisterAddress::CRB_REG_M);
 ///
 /// [`MR_REG_M`](RegisterAddress::MR_REG_M) (02h)
 #[bitfield(u8, order = Msb)]
-#[derive(PartialEq, Eq)]
+#[derive(PartialEq, Eq, Hash)]
+#[must_use]
 #[cfg_attr(feature = "defmt", derive(defmt::Format))]
 pub struct ModeRegisterM {
     /// Must be zero for correct operation of the device.
@@ -144,6 +216,19 @@ pub struct ModeRegisterM {
 
 writable_register!(ModeRegisterM, RegisterAddress::MR_REG_M);
 
+impl ModeRegisterM {
+    /// Returns a register triggering a single conversion.
+    ///
+    /// Writing this register wakes the magnetometer, which then performs one measurement
+    /// and returns to sleep mode by itself. Once [`StatusRegisterM::data_ready`] is set,
+    /// the reading can be obtained from the output registers.
+    pub const fn single_shot() -> Self {
+        Self::new()
+            .with_sleep_mode(false)
+            .with_single_conversion(true)
+    }
+}
+
 /// [`OUT_X_H_M`](RegisterAddress::OUT_X_H_M) (03h)
 ///
 /// High byte of the 16-bit acceleration value. See [`OutXLowM`] for the low byte.
@@ -158,7 +243,8 @@ writable_register!(ModeRegisterM, RegisterAddress::MR_REG_M);
 /// has the lower register address and will be read first.
 /// This is different from the accelerometer and temperature reading registers.
 #[bitfield(u8, order = Msb)]
-#[derive(PartialEq, Eq)]
+#[derive(PartialEq, Eq, Hash)]
+#[must_use]
 #[cfg_attr(feature = "defmt", derive(defmt::Format))]
 pub struct OutXHighM {
     /// High byte of the X-axis magnetic field value.
@@ -184,7 +270,8 @@ readable_register!(OutXHighM, RegisterAddress::OUT_X_H_M);
 /// has the lower register address and will be read first.
 /// This is different from the accelerometer and temperature reading registers.
 #[bitfield(u8, order = Msb)]
-#[derive(PartialEq, Eq)]
+#[derive(PartialEq, Eq, Hash)]
+#[must_use]
 #[cfg_attr(feature = "defmt", derive(defmt::Format))]
 pub struct OutXLowM {
     /// Low byte of the X-axis magnetic field value.
@@ -210,7 +297,8 @@ readable_register!(OutXLowM, RegisterAddress::OUT_X_L_M);
 /// has the lower register address and will be read first.
 /// This is different from the accelerometer and temperature reading registers.
 #[bitfield(u8, order = Msb)]
-#[derive(PartialEq, Eq)]
+#[derive(PartialEq, Eq, Hash)]
+#[must_use]
 #[cfg_attr(feature = "defmt", derive(defmt::Format))]
 pub struct OutZHighM {
     /// High byte of the Z-axis magnetic field value.
@@ -236,7 +324,8 @@ readable_register!(OutZHighM, RegisterAddress::OUT_Z_H_M);
 /// has the lower register address and will be read first.
 /// This is different from the accelerometer and temperature reading registers.
 #[bitfield(u8, order = Msb)]
-#[derive(PartialEq, Eq)]
+#[derive(PartialEq, Eq, Hash)]
+#[must_use]
 #[cfg_attr(feature = "defmt", derive(defmt::Format))]
 pub struct OutZLowM {
     /// Low byte of the Z-axis magnetic field value.
@@ -262,7 +351,8 @@ readable_register!(OutZLowM, RegisterAddress::OUT_Z_L_M);
 /// has the lower register address and will be read first.
 /// This is different from the accelerometer and temperature reading registers.
 #[bitfield(u8, order = Msb)]
-#[derive(PartialEq, Eq)]
+#[derive(PartialEq, Eq, Hash)]
+#[must_use]
 #[cfg_attr(feature = "defmt", derive(defmt::Format))]
 pub struct OutYHighM {
     /// High byte of the Y-axis magnetic field value.
@@ -288,7 +378,8 @@ readable_register!(OutYHighM, RegisterAddress::OUT_Y_H_M);
 /// has the lower register address and will be read first.
 /// This is different from the accelerometer and temperature reading registers.
 #[bitfield(u8, order = Msb)]
-#[derive(PartialEq, Eq)]
+#[derive(PartialEq, Eq, Hash)]
+#[must_use]
 #[cfg_attr(feature = "defmt", derive(defmt::Format))]
 pub struct OutYLowM {
     /// Low byte of the Y-axis magnetic field value.
@@ -302,7 +393,8 @@ readable_register!(OutYLowM, RegisterAddress::OUT_Y_L_M);
 
 /// [`SR_REG_M`](RegisterAddress::SR_REG_M) (09h)
 #[bitfield(u8, order = Msb)]
-#[derive(PartialEq, Eq)]
+#[derive(PartialEq, Eq, Hash)]
+#[must_use]
 #[cfg_attr(feature = "defmt", derive(defmt::Format))]
 pub struct StatusRegisterM {
     #[bits(6)]
@@ -320,12 +412,32 @@ pub struct StatusRegisterM {
 
 readable_register!(StatusRegisterM, RegisterAddress::SR_REG_M);
 
+impl StatusRegisterM {
+    /// Determines whether a fresh set of measurements is ready to be read.
+    ///
+    /// This is the case if [`data_ready`](Self::data_ready) is set and the output registers
+    /// are not [`locked`](Self::locked).
+    pub const fn sample_ready(&self) -> bool {
+        self.data_ready() && !self.do_lock()
+    }
+
+    /// Determines whether the data output registers are locked.
+    ///
+    /// The lock is set once the first magnetic field data register of a new set of
+    /// measurements has been read, and is released when the remaining output registers
+    /// have been read. While locked, the output registers are not updated.
+    pub const fn locked(&self) -> bool {
+        self.do_lock()
+    }
+}
+
 /// The identification registers (IR) are used to identify the device.
 /// (See Doc ID 16941 Rev 1. for the LSM303DLH, non -C version)
 ///
 /// [`IRA_REG_M`](RegisterAddress::IRA_REG_M) (0Ah)
 #[bitfield(u8, order = Msb)]
-#[derive(PartialEq, Eq)]
+#[derive(PartialEq, Eq, Hash)]
+#[must_use]
 #[cfg_attr(feature = "defmt", derive(defmt::Format))]
 pub struct IdentificationARegisterM {
     /// Undocumented. Always `01001000`, ASCII `H`.
@@ -340,7 +452,8 @@ readable_register!(IdentificationARegisterM, RegisterAddress::IRA_REG_M);
 ///
 /// [`IRB_REG_M`](RegisterAddress::IRB_REG_M) (0Bh)
 #[bitfield(u8, order = Msb)]
-#[derive(PartialEq, Eq)]
+#[derive(PartialEq, Eq, Hash)]
+#[must_use]
 #[cfg_attr(feature = "defmt", derive(defmt::Format))]
 pub struct IdentificationBRegisterM {
     /// Undocumented. Always `00110100`, ASCII `4`.
@@ -355,7 +468,8 @@ readable_register!(IdentificationBRegisterM, RegisterAddress::IRB_REG_M);
 ///
 /// [`IRC_REG_M`](RegisterAddress::IRC_REG_M) (0Ch)
 #[bitfield(u8, order = Msb)]
-#[derive(PartialEq, Eq)]
+#[derive(PartialEq, Eq, Hash)]
+#[must_use]
 #[cfg_attr(feature = "defmt", derive(defmt::Format))]
 pub struct IdentificationCRegisterM {
     /// Undocumented. Always `00110011`, ASCII `3`.
@@ -365,14 +479,29 @@ pub struct IdentificationCRegisterM {
 
 readable_register!(IdentificationCRegisterM, RegisterAddress::IRC_REG_M);
 
-/// [`TEMP_OUT_H_M`](RegisterAddress::TEMP_OUT_H_M) (0Ch)
+/// Determines whether the bytes read from [`IRA_REG_M`](RegisterAddress::IRA_REG_M)
+/// through [`IRC_REG_M`](RegisterAddress::IRC_REG_M) identify an LSM303DLHC, i.e. read `H43`.
+///
+/// The accelerometer has no identification register, so the presence of the
+/// device can only be checked through the magnetometer.
+pub const fn is_lsm303dlhc(ira: u8, irb: u8, irc: u8) -> bool {
+    ira == IdentificationARegisterM::POR_VALUE
+        && irb == IdentificationBRegisterM::POR_VALUE
+        && irc == IdentificationCRegisterM::POR_VALUE
+}
+
+/// [`TEMP_OUT_H_M`](RegisterAddress::TEMP_OUT_H_M) (31h)
 ///
 /// High byte of the 12-bit temperature reading.
 ///
 /// Together with [`TemperatureOutLowM`], the value is expressed as two's complement with
 /// 8 LSB/deg at 12-bit resolution.
+///
+/// The temperature sensor needs to be enabled for this register to hold valid data,
+/// see [`ConfigurationARegisterM::with_temperature`].
 #[bitfield(u8, order = Msb)]
-#[derive(PartialEq, Eq)]
+#[derive(PartialEq, Eq, Hash)]
+#[must_use]
 #[cfg_attr(feature = "defmt", derive(defmt::Format))]
 pub struct TemperatureOutHighM {
     /// The upper
@@ -382,14 +511,18 @@ pub struct TemperatureOutHighM {
 
 readable_register!(TemperatureOutHighM, RegisterAddress::TEMP_OUT_H_M);
 
-/// [`TEMP_OUT_L_M`](RegisterAddress::TEMP_OUT_L_M) (0Ch)
+/// [`TEMP_OUT_L_M`](RegisterAddress::TEMP_OUT_L_M) (32h)
 ///
 /// Low byte of the 12-bit temperature reading.
 ///
 /// Together with [`TemperatureOutLowM`], the value is expressed as two's complement with
 /// 8 LSB/deg at 12-bit resolution.
+///
+/// The temperature sensor needs to be enabled for this register to hold valid data,
+/// see [`ConfigurationARegisterM::with_temperature`].
 #[bitfield(u8, order = Msb)]
-#[derive(PartialEq, Eq)]
+#[derive(PartialEq, Eq, Hash)]
+#[must_use]
 #[cfg_attr(feature = "defmt", derive(defmt::Format))]
 pub struct TemperatureOutLowM {
     /// The lower nibble of the temperature reading.
@@ -402,6 +535,60 @@ pub struct TemperatureOutLowM {
 
 readable_register!(TemperatureOutLowM, RegisterAddress::TEMP_OUT_L_M);
 
+register_masks! {
+    ConfigurationARegisterM => (0x9C, 0x63),
+    ConfigurationBRegisterM => (0xE0, 0x1F),
+    ModeRegisterM => (0x03, 0xFC),
+    OutXHighM => (0x00, 0x00),
+    OutXLowM => (0x00, 0x00),
+    OutZHighM => (0x00, 0x00),
+    OutZLowM => (0x00, 0x00),
+    OutYHighM => (0x00, 0x00),
+    OutYLowM => (0x00, 0x00),
+    StatusRegisterM => (0x00, 0xFC),
+    IdentificationARegisterM => (0x00, 0x00),
+    IdentificationBRegisterM => (0x00, 0x00),
+    IdentificationCRegisterM => (0x00, 0x00),
+    TemperatureOutHighM => (0x00, 0x00),
+    TemperatureOutLowM => (0x00, 0x0F),
+}
+
+register_por_values! {
+    ConfigurationARegisterM => 0b0001_0000,
+    ConfigurationBRegisterM => 0b0010_0000,
+    ModeRegisterM => 0b0000_0011,
+    OutXHighM => 0x00,
+    OutXLowM => 0x00,
+    OutZHighM => 0x00,
+    OutZLowM => 0x00,
+    OutYHighM => 0x00,
+    OutYLowM => 0x00,
+    StatusRegisterM => 0x00,
+    IdentificationARegisterM => 0b0100_1000,
+    IdentificationBRegisterM => 0b0011_0100,
+    IdentificationCRegisterM => 0b0011_0011,
+    TemperatureOutHighM => 0x00,
+    TemperatureOutLowM => 0x00,
+}
+
+assert_register_addresses! {
+    ConfigurationARegisterM => 0x00,
+    ConfigurationBRegisterM => 0x01,
+    ModeRegisterM => 0x02,
+    OutXHighM => 0x03,
+    OutXLowM => 0x04,
+    OutZHighM => 0x05,
+    OutZLowM => 0x06,
+    OutYHighM => 0x07,
+    OutYLowM => 0x08,
+    StatusRegisterM => 0x09,
+    IdentificationARegisterM => 0x0A,
+    IdentificationBRegisterM => 0x0B,
+    IdentificationCRegisterM => 0x0C,
+    TemperatureOutHighM => 0x31,
+    TemperatureOutLowM => 0x32,
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -411,4 +598,261 @@ mod tests {
         let value = ConfigurationARegisterM::new();
         assert_eq!(value.into_bits(), 0b0010000);
     }
+
+    #[test]
+    fn cra_sample_rate() {
+        let value = ConfigurationARegisterM::from_bits(0b0001_1000);
+        assert_eq!(value.data_output_rate(), MagOdr::Hz75);
+        assert_eq!(value.data_output_rate().frequency_hz(), 75.0);
+    }
+
+    #[test]
+    fn lenient_default_rate() {
+        assert_eq!(MagOdr::from_bits_lenient(0b0001_0000), MagOdr::Hz15);
+        assert_eq!(MagOdr::from_bits_lenient(0b0000_1000), MagOdr::Hz15);
+        assert_eq!(MagOdr::from_bits_lenient(0b1001_1100), MagOdr::Hz220);
+    }
+
+    #[test]
+    fn cra_temperature() {
+        let value = ConfigurationARegisterM::new().with_temperature(true);
+        assert!(value.temp_en());
+        assert_eq!(value.into_bits(), 0b1001_0000);
+        assert_eq!(
+            value.with_temperature(false),
+            ConfigurationARegisterM::new()
+        );
+    }
+
+    #[test]
+    fn cra_masks() {
+        assert_eq!(ConfigurationARegisterM::WRITABLE_MASK, 0b1001_1100);
+        assert_eq!(ConfigurationARegisterM::RESERVED_MASK, 0b0110_0011);
+        let all = ConfigurationARegisterM::new()
+            .with_temperature(true)
+            .with_data_output_rate(MagOdr::Hz220);
+        assert_eq!(all.into_bits(), ConfigurationARegisterM::WRITABLE_MASK);
+    }
+
+    #[test]
+    fn gain_auto_ranging() {
+        assert_eq!(MagGain::Gauss1_3.coarser(), Some(MagGain::Gauss1_9));
+        assert_eq!(MagGain::Gauss8_1.coarser(), None);
+        assert_eq!(MagGain::Gauss1_3.finer(), None);
+    }
+
+    #[test]
+    fn gain_code_round_trip() {
+        for code in 0b001..=0b111 {
+            let gain = MagGain::try_from(code).unwrap();
+            assert_eq!(u8::from(gain), code);
+        }
+        assert_eq!(
+            MagGain::try_from(0),
+            Err(crate::RegisterError::ValueOutOfRange)
+        );
+        assert!(MagOdr::try_from(0b1000).is_err());
+    }
+
+    #[test]
+    fn single_shot() {
+        let mode = ModeRegisterM::single_shot();
+        assert!(mode.single_conversion());
+        assert!(!mode.sleep_mode());
+        assert_eq!(mode, 0b0000_0001);
+    }
+
+    #[test]
+    fn gain_map_key() {
+        let mut scales = heapless::FnvIndexMap::<MagGain, u16, 8>::new();
+        scales.insert(MagGain::Gauss1_3, 1100).unwrap();
+        scales.insert(MagGain::Gauss8_1, 230).unwrap();
+        assert_eq!(scales.get(&MagGain::Gauss8_1), Some(&230));
+        assert_eq!(scales.get(&MagGain::Gauss4_0), None);
+    }
+
+    #[test]
+    fn register_access() {
+        assert!(RegisterAddress::MR_REG_M.is_writable());
+        assert!(!RegisterAddress::SR_REG_M.is_writable());
+        assert!(!RegisterAddress::IRA_REG_M.is_writable());
+        assert!(RegisterAddress::TEMP_OUT_H_M.is_readable());
+    }
+
+    #[test]
+    fn undefined_gain_does_not_panic() {
+        let value = ConfigurationBRegisterM::from_bits(0x00);
+        assert_eq!(value.gain(), MagGain::Gauss1_3);
+        assert_eq!(value.into_bits(), 0x00);
+    }
+
+    #[test]
+    fn writable_bits_round_trip() {
+        macro_rules! round_trip {
+            ($($type:ident),+ $(,)?) => {$(
+                for b in 0..=u8::MAX {
+                    assert_eq!(
+                        $type::from_bits(b).into_bits() & $type::WRITABLE_MASK,
+                        b & $type::WRITABLE_MASK,
+                        concat!(stringify!($type), " does not round-trip {:#04X}"),
+                        b
+                    );
+                }
+            )+};
+        }
+
+        round_trip!(
+            ConfigurationARegisterM,
+            ConfigurationBRegisterM,
+            ModeRegisterM
+        );
+    }
+
+    #[test]
+    fn identification() {
+        assert!(is_lsm303dlhc(b'H', b'4', b'3'));
+        assert!(!is_lsm303dlhc(b'H', b'4', b'0'));
+        assert!(!is_lsm303dlhc(0x00, 0x00, 0x00));
+    }
+
+    #[test]
+    fn por_values_match_defaults() {
+        macro_rules! assert_por {
+            ($($type:ident),+ $(,)?) => {$(
+                assert_eq!(
+                    $type::new().into_bits(),
+                    $type::POR_VALUE,
+                    concat!(stringify!($type), " defaults differ from the datasheet reset value")
+                );
+            )+};
+        }
+
+        assert_por!(
+            ConfigurationARegisterM,
+            ConfigurationBRegisterM,
+            ModeRegisterM,
+            OutXHighM,
+            OutXLowM,
+            OutZHighM,
+            OutZLowM,
+            OutYHighM,
+            OutYLowM,
+            StatusRegisterM,
+            IdentificationARegisterM,
+            IdentificationBRegisterM,
+            IdentificationCRegisterM,
+            TemperatureOutHighM,
+            TemperatureOutLowM,
+        );
+    }
+
+    #[test]
+    fn gain_lookup() {
+        assert_eq!(
+            MagGain::from_xy_lsb_per_gauss(1100),
+            Some(MagGain::Gauss1_3)
+        );
+        assert_eq!(MagGain::from_xy_lsb_per_gauss(1000), None);
+        assert_eq!(MagGain::from_range_gauss(8.1), Some(MagGain::Gauss8_1));
+        assert_eq!(MagGain::from_range_gauss(3.0), None);
+    }
+
+    #[test]
+    fn gain_for_max_field() {
+        assert_eq!(MagGain::for_max_field_gauss(0.6), MagGain::Gauss1_3);
+        assert_eq!(MagGain::for_max_field_gauss(1.3), MagGain::Gauss1_3);
+        assert_eq!(MagGain::for_max_field_gauss(2.0), MagGain::Gauss2_5);
+        assert_eq!(MagGain::for_max_field_gauss(5.0), MagGain::Gauss5_6);
+        assert_eq!(MagGain::for_max_field_gauss(12.0), MagGain::Gauss8_1);
+    }
+
+    #[test]
+    fn out_address_order() {
+        assert!(
+            MAG_OUT_ADDRESSES
+                == [
+                    RegisterAddress::OUT_X_H_M,
+                    RegisterAddress::OUT_X_L_M,
+                    RegisterAddress::OUT_Z_H_M,
+                    RegisterAddress::OUT_Z_L_M,
+                    RegisterAddress::OUT_Y_H_M,
+                    RegisterAddress::OUT_Y_L_M,
+                ]
+        );
+
+        assert_eq!(MAG_OUT_START, 0x03);
+        assert_eq!(MAG_OUT_LEN, MAG_OUT_ADDRESSES.len());
+
+        let reading = MagReading::from_register_bytes([0x00, 0x01, 0x00, 0x03, 0xFF, 0xFE]);
+        assert_eq!(reading, MagReading::new(1, -2, 3));
+    }
+
+    #[test]
+    fn reading_array_round_trip() {
+        let reading = MagReading::new(1, -2, 3);
+        assert_eq!((reading.x, reading.y, reading.z), (1, -2, 3));
+
+        let array: [i16; 3] = reading.into();
+        assert_eq!(array, [1, -2, 3]);
+        assert_eq!(MagReading::from(array), reading);
+    }
+
+    #[test]
+    fn reading_from_slice() {
+        let bytes = [0x00, 0x01, 0x00, 0x03, 0xFF, 0xFE, 0x00];
+        assert_eq!(
+            MagReading::try_from(&bytes[..6]),
+            Ok(MagReading::new(1, -2, 3))
+        );
+        assert!(MagReading::try_from(&bytes[..5]).is_err());
+        assert!(MagReading::try_from(&bytes[..]).is_err());
+    }
+
+    #[test]
+    fn reading_saturation() {
+        assert!(!MagReading::new(100, -200, 300).is_saturated());
+        assert!(MagReading::new(100, -4096, 300).is_saturated());
+    }
+
+    #[test]
+    fn reading_overflow() {
+        let reading = MagReading::new(1100, MagReading::OVERFLOW, -980);
+        assert_eq!(reading.overflowed_axes(), crate::accel::Axes::Y);
+
+        let MagGauss([x, y, z]) = reading.to_gauss(MagGain::Gauss1_3);
+        assert_eq!((x, z), (1.0, -1.0));
+        assert!(y.is_nan());
+    }
+
+    #[test]
+    fn temperature_reading() {
+        let reading = TemperatureReading::from_registers(
+            TemperatureOutHighM::from_bits(0x01),
+            TemperatureOutLowM::from_bits(0x80),
+        );
+        assert_eq!(reading.raw, 24);
+        assert_eq!(reading.celsius(), 3.0);
+
+        let reading = TemperatureReading::from_registers(
+            TemperatureOutHighM::from_bits(0xFF),
+            TemperatureOutLowM::from_bits(0xF0),
+        );
+        assert_eq!(reading.raw, -1);
+        assert_eq!(reading.celsius(), -0.125);
+    }
+
+    #[test]
+    fn status_sample_ready() {
+        let cases = [
+            (0b00, false, false),
+            (0b01, true, false),
+            (0b10, false, true),
+            (0b11, false, true),
+        ];
+        for (bits, ready, locked) in cases {
+            let status = StatusRegisterM::from_bits(bits);
+            assert_eq!(status.sample_ready(), ready);
+            assert_eq!(status.locked(), locked);
+        }
+    }
 }
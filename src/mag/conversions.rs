@@ -1,11 +1,161 @@
+use crate::accel::Axes;
 use crate::mag::*;
+use crate::{assemble_i16, RegisterError};
 use core::ops::Add;
 
+/// A raw magnetometer reading, assembled from the `OUT_*_M` registers.
+///
+/// The fields are stored in the X-Z-Y order of the sensor's output registers;
+/// array conversions use the conventional X-Y-Z order.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+pub struct MagReading {
+    /// The X-axis value.
+    pub x: i16,
+    /// The Z-axis value.
+    pub z: i16,
+    /// The Y-axis value.
+    pub y: i16,
+}
+
+impl MagReading {
+    /// Creates a new reading from the axis values.
+    pub const fn new(x: i16, y: i16, z: i16) -> Self {
+        Self { x, z, y }
+    }
+
+    /// The value reported on an axis whose field exceeds the configured [`MagGain`] range.
+    pub const OVERFLOW: i16 = -4096;
+
+    /// Determines whether any axis reports the [`OVERFLOW`](Self::OVERFLOW) value.
+    pub const fn is_saturated(&self) -> bool {
+        self.x == Self::OVERFLOW || self.y == Self::OVERFLOW || self.z == Self::OVERFLOW
+    }
+
+    /// Returns the axes that report the [`OVERFLOW`](Self::OVERFLOW) value.
+    pub const fn overflowed_axes(&self) -> Axes {
+        let mut axes = Axes::NONE;
+        if self.x == Self::OVERFLOW {
+            axes = axes.union(Axes::X);
+        }
+        if self.y == Self::OVERFLOW {
+            axes = axes.union(Axes::Y);
+        }
+        if self.z == Self::OVERFLOW {
+            axes = axes.union(Axes::Z);
+        }
+        axes
+    }
+
+    /// Converts the reading to Gauss in X-Y-Z order, using the gain the reading was taken with.
+    ///
+    /// Axes that report the [`OVERFLOW`](Self::OVERFLOW) value are converted to `NaN`.
+    pub fn to_gauss(&self, gain: MagGain) -> MagGauss {
+        let xy = gain.xy_lsb_per_gauss() as f32;
+        let z = gain.z_lsb_per_gauss() as f32;
+        let convert = |value: i16, lsb_per_gauss: f32| {
+            if value == Self::OVERFLOW {
+                f32::NAN
+            } else {
+                value as f32 / lsb_per_gauss
+            }
+        };
+        MagGauss([convert(self.x, xy), convert(self.y, xy), convert(self.z, z)])
+    }
+
+    /// Assembles a reading from the bytes of the registers in [`MAG_OUT_ADDRESSES`], in that order.
+    pub const fn from_register_bytes(bytes: [u8; 6]) -> Self {
+        let [xh, xl, zh, zl, yh, yl] = bytes;
+        Self::new(
+            assemble_i16(xl, xh),
+            assemble_i16(yl, yh),
+            assemble_i16(zl, zh),
+        )
+    }
+}
+
+impl From<MagReading> for [i16; 3] {
+    fn from(value: MagReading) -> Self {
+        [value.x, value.y, value.z]
+    }
+}
+
+impl From<[i16; 3]> for MagReading {
+    fn from(value: [i16; 3]) -> Self {
+        Self::new(value[0], value[1], value[2])
+    }
+}
+
+/// Assembles a reading from the six bytes read from [`OUT_X_H_M`](RegisterAddress::OUT_X_H_M)
+/// through [`OUT_Y_L_M`](RegisterAddress::OUT_Y_L_M), i.e. in X-Z-Y and big endian order.
+impl TryFrom<&[u8]> for MagReading {
+    type Error = RegisterError;
+
+    fn try_from(bytes: &[u8]) -> Result<Self, Self::Error> {
+        match *bytes {
+            [xh, xl, zh, zl, yh, yl] => Ok(Self::from_register_bytes([xh, xl, zh, zl, yh, yl])),
+            _ => Err(RegisterError::InvalidLength {
+                expected: 6,
+                actual: bytes.len(),
+            }),
+        }
+    }
+}
+
+/// A magnetic field in Gauss, in X-Y-Z order; see [`MagReading::to_gauss`].
+///
+/// Formats as e.g. `x=0.21G y=-0.05G z=0.43G`, including when logging via `defmt`.
+#[derive(Debug, Default, Clone, Copy, PartialEq)]
+pub struct MagGauss(pub [f32; 3]);
+
+impl core::fmt::Display for MagGauss {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        let [x, y, z] = self.0;
+        write!(f, "x={x:.2}G y={y:.2}G z={z:.2}G")
+    }
+}
+
+#[cfg(feature = "defmt")]
+impl defmt::Format for MagGauss {
+    fn format(&self, f: defmt::Formatter) {
+        let [x, y, z] = self.0;
+        defmt::write!(f, "x={=f32}G y={=f32}G z={=f32}G", x, y, z);
+    }
+}
+
+/// A raw temperature reading, assembled from the `TEMP_OUT_*_M` registers.
+///
+/// The sensor provides the temperature as a 12-bit two's complement value with 8 LSB/°C.
+/// The temperature sensor needs to be enabled using
+/// [`ConfigurationARegisterM::with_temperature`] for the reading to be valid.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+pub struct TemperatureReading {
+    /// The sign-extended 12-bit value.
+    pub raw: i16,
+}
+
+impl TemperatureReading {
+    /// Assembles the reading from the high and low temperature registers.
+    pub const fn from_registers(high: TemperatureOutHighM, low: TemperatureOutLowM) -> Self {
+        let raw = ((high.value() as i8 as i16) << 4) | (low.value() as i16);
+        Self { raw }
+    }
+
+    /// Converts the reading to degrees Celsius at 8 LSB/°C.
+    ///
+    /// The sensor is not calibrated for an absolute offset, so the value is
+    /// best used to track changes in temperature.
+    pub fn celsius(&self) -> f32 {
+        self.raw as f32 / 8.0
+    }
+}
+
 impl Add<OutXHighM> for OutXLowM {
     type Output = i16;
 
     fn add(self, hi: OutXHighM) -> Self::Output {
-        (hi.bits() as i16) << 8 | (self.bits() as i16)
+        assemble_i16(self.bits(), hi.bits())
     }
 }
 
@@ -21,7 +171,7 @@ impl Add<OutYHighM> for OutYLowM {
     type Output = i16;
 
     fn add(self, hi: OutYHighM) -> Self::Output {
-        (hi.bits() as i16) << 8 | (self.bits() as i16)
+        assemble_i16(self.bits(), hi.bits())
     }
 }
 
@@ -37,7 +187,7 @@ impl Add<OutZHighM> for OutZLowM {
     type Output = i16;
 
     fn add(self, hi: OutZHighM) -> Self::Output {
-        (hi.bits() as i16) << 8 | (self.bits() as i16)
+        assemble_i16(self.bits(), hi.bits())
     }
 }
 
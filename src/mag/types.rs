@@ -1,9 +1,10 @@
 //! Types used in the magnetometer registers.
 
 /// Magnetometer Output Data Rate
-#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
 #[cfg_attr(feature = "defmt", derive(defmt::Format))]
 #[repr(u8)]
+#[non_exhaustive]
 pub enum MagOdr {
     /// 0.75 Hz (`0b000`)
     Hz0_75 = 0b000,
@@ -42,12 +43,49 @@ impl MagOdr {
             _ => unreachable!(),
         }
     }
+
+    /// Decodes the data output rate from a raw [`CRA_REG_M`](crate::mag::RegisterAddress::CRA_REG_M)
+    /// value, tolerating the datasheet's inconsistent documentation of the default value.
+    ///
+    /// Section 7.2.1 documents the default data output rate bits as `100`, i.e. a register
+    /// value of `0b0001_0000`. Table 17 documents the default as `0001000`, which is missing
+    /// a zero and reads as `0b0000_1000`. Both values are accepted and decode to [`MagOdr::Hz15`];
+    /// all other values are decoded from the data output rate bits `DO2..DO0` (bits 4 to 2).
+    ///
+    /// Note that this maps a register value of `0b0000_1000` to 15 Hz, not 3 Hz.
+    pub const fn from_bits_lenient(value: u8) -> Self {
+        match value {
+            0b0001_0000 | 0b0000_1000 => MagOdr::Hz15,
+            _ => Self::from_bits((value >> 2) & 0b111),
+        }
+    }
+
+    /// Returns the output data rate in Hz.
+    pub const fn frequency_hz(self) -> f32 {
+        match self {
+            MagOdr::Hz0_75 => 0.75,
+            MagOdr::Hz1_5 => 1.5,
+            MagOdr::Hz3 => 3.0,
+            MagOdr::Hz7_5 => 7.5,
+            MagOdr::Hz15 => 15.0,
+            MagOdr::Hz30 => 30.0,
+            MagOdr::Hz75 => 75.0,
+            MagOdr::Hz220 => 220.0,
+        }
+    }
+
+    /// Returns the data rate for formatting as its frequency, e.g. `0.75 Hz`;
+    /// see [`frequency_hz`](Self::frequency_hz).
+    pub const fn display_hz(self) -> crate::DisplayHz {
+        crate::DisplayHz(self.frequency_hz())
+    }
 }
 
 /// Magnetometer gain configuration.
-#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
 #[cfg_attr(feature = "defmt", derive(defmt::Format))]
 #[repr(u8)]
+#[non_exhaustive]
 pub enum MagGain {
     /// Sensor input field range ±1.3 Gauss.
     ///
@@ -101,7 +139,135 @@ impl MagGain {
             0b101 => MagGain::Gauss4_7,
             0b110 => MagGain::Gauss5_6,
             0b111 => MagGain::Gauss8_1,
-            _ => unreachable!(),
+            // 0b000 is undefined and treated as the smallest field range.
+            _ => MagGain::Gauss1_3,
+        }
+    }
+
+    /// All gain settings, in ascending order of their field range.
+    const ALL: [MagGain; 7] = [
+        MagGain::Gauss1_3,
+        MagGain::Gauss1_9,
+        MagGain::Gauss2_5,
+        MagGain::Gauss4_0,
+        MagGain::Gauss4_7,
+        MagGain::Gauss5_6,
+        MagGain::Gauss8_1,
+    ];
+
+    /// Returns the next larger field range, or `None` if this is the largest one.
+    pub const fn coarser(self) -> Option<Self> {
+        match self {
+            MagGain::Gauss1_3 => Some(MagGain::Gauss1_9),
+            MagGain::Gauss1_9 => Some(MagGain::Gauss2_5),
+            MagGain::Gauss2_5 => Some(MagGain::Gauss4_0),
+            MagGain::Gauss4_0 => Some(MagGain::Gauss4_7),
+            MagGain::Gauss4_7 => Some(MagGain::Gauss5_6),
+            MagGain::Gauss5_6 => Some(MagGain::Gauss8_1),
+            MagGain::Gauss8_1 => None,
+        }
+    }
+
+    /// Returns the next smaller field range, or `None` if this is the smallest one.
+    pub const fn finer(self) -> Option<Self> {
+        match self {
+            MagGain::Gauss1_3 => None,
+            MagGain::Gauss1_9 => Some(MagGain::Gauss1_3),
+            MagGain::Gauss2_5 => Some(MagGain::Gauss1_9),
+            MagGain::Gauss4_0 => Some(MagGain::Gauss2_5),
+            MagGain::Gauss4_7 => Some(MagGain::Gauss4_0),
+            MagGain::Gauss5_6 => Some(MagGain::Gauss4_7),
+            MagGain::Gauss8_1 => Some(MagGain::Gauss5_6),
         }
     }
+
+    /// Returns the sensor input field range in Gauss.
+    pub const fn range_gauss(self) -> f32 {
+        match self {
+            MagGain::Gauss1_3 => 1.3,
+            MagGain::Gauss1_9 => 1.9,
+            MagGain::Gauss2_5 => 2.5,
+            MagGain::Gauss4_0 => 4.0,
+            MagGain::Gauss4_7 => 4.7,
+            MagGain::Gauss5_6 => 5.6,
+            MagGain::Gauss8_1 => 8.1,
+        }
+    }
+
+    /// Returns the X and Y axis gain in LSB/Gauss.
+    pub const fn xy_lsb_per_gauss(self) -> u16 {
+        match self {
+            MagGain::Gauss1_3 => 1100,
+            MagGain::Gauss1_9 => 855,
+            MagGain::Gauss2_5 => 670,
+            MagGain::Gauss4_0 => 450,
+            MagGain::Gauss4_7 => 400,
+            MagGain::Gauss5_6 => 330,
+            MagGain::Gauss8_1 => 230,
+        }
+    }
+
+    /// Returns the Z axis gain in LSB/Gauss.
+    pub const fn z_lsb_per_gauss(self) -> u16 {
+        match self {
+            MagGain::Gauss1_3 => 980,
+            MagGain::Gauss1_9 => 760,
+            MagGain::Gauss2_5 => 600,
+            MagGain::Gauss4_0 => 400,
+            MagGain::Gauss4_7 => 355,
+            MagGain::Gauss5_6 => 295,
+            MagGain::Gauss8_1 => 205,
+        }
+    }
+
+    /// Finds the gain setting with the specified X and Y axis gain in LSB/Gauss.
+    pub fn from_xy_lsb_per_gauss(value: u16) -> Option<Self> {
+        Self::ALL
+            .into_iter()
+            .find(|gain| gain.xy_lsb_per_gauss() == value)
+    }
+
+    /// Finds the gain setting with the specified field range in Gauss.
+    ///
+    /// The range is matched within ±0.01 Gauss to tolerate rounding in stored values.
+    pub fn from_range_gauss(value: f32) -> Option<Self> {
+        const EPSILON: f32 = 0.01;
+        Self::ALL.into_iter().find(|gain| {
+            let delta = gain.range_gauss() - value;
+            delta < EPSILON && delta > -EPSILON
+        })
+    }
+
+    /// Returns the smallest gain setting whose field range covers `value` Gauss,
+    /// saturating at [`Gauss8_1`](Self::Gauss8_1).
+    ///
+    /// The geomagnetic field is roughly 0.25 to 0.65 Gauss, but nearby magnets
+    /// and ferrous materials may require a larger range.
+    pub fn for_max_field_gauss(value: f32) -> Self {
+        Self::ALL
+            .into_iter()
+            .find(|gain| gain.range_gauss() >= value)
+            .unwrap_or(MagGain::Gauss8_1)
+    }
 }
+
+enum_conversions!(MagOdr {
+    Hz0_75,
+    Hz1_5,
+    Hz3,
+    Hz7_5,
+    Hz15,
+    Hz30,
+    Hz75,
+    Hz220
+});
+
+enum_conversions!(MagGain {
+    Gauss1_3,
+    Gauss1_9,
+    Gauss2_5,
+    Gauss4_0,
+    Gauss4_7,
+    Gauss5_6,
+    Gauss8_1
+});
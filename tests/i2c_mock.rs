@@ -0,0 +1,81 @@
+//! Register round-trips through a mocked I²C bus.
+
+use embedded_hal::i2c::I2c;
+use embedded_hal_mock::eh1::i2c::{Mock, Transaction};
+use lsm303dlhc_registers::accel::*;
+use lsm303dlhc_registers::{Register, WritableRegister};
+
+#[test]
+fn write_control_register_and_read_status() {
+    let ctrl1 = ControlRegister1A::new().with_output_data_rate(AccelOdr::Hz50);
+    let expectations = [
+        Transaction::write(DEFAULT_DEVICE_ADDRESS, vec![0x20, 0x47]),
+        Transaction::write_read(DEFAULT_DEVICE_ADDRESS, vec![0x27], vec![0b0000_1111]),
+    ];
+    let mut i2c = Mock::new(&expectations);
+
+    let (device, register, value) = ctrl1.write_tuple();
+    i2c.write(device, &[register, value]).unwrap();
+
+    let mut buf = [0u8; 1];
+    i2c.write_read(
+        DEFAULT_DEVICE_ADDRESS,
+        &StatusRegisterA::read_command(false),
+        &mut buf,
+    )
+    .unwrap();
+    let status = StatusRegisterA::from_bits(buf[0]);
+    assert!(status.xyz_data_available());
+    assert!(status.x_data_available());
+    assert!(!status.zyx_overrun());
+
+    i2c.done();
+}
+
+#[test]
+fn burst_read_output_registers() {
+    let expectations = [Transaction::write_read(
+        DEFAULT_DEVICE_ADDRESS,
+        vec![0xA8],
+        vec![0x00, 0x40, 0x00, 0xC0, 0x10, 0x00],
+    )];
+    let mut i2c = Mock::new(&expectations);
+
+    let mut buf = [0u8; ACCEL_OUT_LEN];
+    i2c.write_read(
+        DEFAULT_DEVICE_ADDRESS,
+        &OutXLowA::read_command(true),
+        &mut buf,
+    )
+    .unwrap();
+    let reading = AccelReading::try_from(&buf[..]).unwrap();
+    assert_eq!(reading, AccelReading::new(0x4000, -0x4000, 0x0010));
+
+    i2c.done();
+}
+
+#[test]
+fn identify_magnetometer() {
+    use lsm303dlhc_registers::mag::{self, IdentificationARegisterM};
+
+    let expectations = [
+        Transaction::write_read(mag::DEFAULT_DEVICE_ADDRESS, vec![0x0A], b"H43".to_vec()),
+        Transaction::write_read(mag::DEFAULT_DEVICE_ADDRESS, vec![0x0A], b"H40".to_vec()),
+    ];
+    let mut i2c = Mock::new(&expectations);
+
+    let mut identify = || {
+        let mut id = [0u8; 3];
+        i2c.write_read(
+            mag::DEFAULT_DEVICE_ADDRESS,
+            &IdentificationARegisterM::read_command(false),
+            &mut id,
+        )
+        .unwrap();
+        mag::is_lsm303dlhc(id[0], id[1], id[2])
+    };
+    assert!(identify());
+    assert!(!identify());
+
+    i2c.done();
+}